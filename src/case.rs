@@ -0,0 +1,128 @@
+//! Case conversion for generated method names.
+
+/// Naming rule set with `rename_all = "..."`. Only rules producing valid identifiers are
+/// supported so there is no kebab case.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+}
+
+impl RenameRule {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "lowercase" => Some(RenameRule::Lower),
+            "UPPERCASE" => Some(RenameRule::Upper),
+            "PascalCase" => Some(RenameRule::Pascal),
+            "camelCase" => Some(RenameRule::Camel),
+            "snake_case" => Some(RenameRule::Snake),
+            "SCREAMING_SNAKE_CASE" => Some(RenameRule::ScreamingSnake),
+            _ => None,
+        }
+    }
+
+    /// Convert `name`, which may be in snake or pascal case. Leading underscores are kept
+    /// as they are.
+    pub fn apply(self, name: &str) -> String {
+        let trimmed = name.trim_start_matches('_');
+        let leading = &name[..name.len() - trimmed.len()];
+        let words = words(trimmed);
+
+        let converted = match self {
+            RenameRule::Lower => words.concat().to_lowercase(),
+            RenameRule::Upper => words.concat().to_uppercase(),
+            RenameRule::Pascal => words
+                .iter()
+                .map(|word| capitalise(word))
+                .collect::<String>(),
+            RenameRule::Camel => words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| if i == 0 { word.to_lowercase() } else { capitalise(word) })
+                .collect::<String>(),
+            RenameRule::Snake => words
+                .iter()
+                .map(|word| word.to_lowercase())
+                .collect::<Vec<_>>()
+                .join("_"),
+            RenameRule::ScreamingSnake => words
+                .iter()
+                .map(|word| word.to_uppercase())
+                .collect::<Vec<_>>()
+                .join("_"),
+        };
+
+        format!("{}{}", leading, converted)
+    }
+}
+
+/// Split on underscores and on a lower case letter or digit followed by an upper case one.
+fn words(name: &str) -> Vec<String> {
+    let mut words: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut previous: Option<char> = None;
+
+    for c in name.chars() {
+        if c == '_' {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+        } else {
+            let boundary = c.is_uppercase()
+                && previous.is_some_and(|p| p.is_lowercase() || p.is_ascii_digit());
+            if boundary && !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            current.push(c);
+        }
+        previous = Some(c);
+    }
+
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+fn capitalise(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect(),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn split_words() {
+        assert!(words("first_name") == vec!["first", "name"]);
+        assert!(words("FirstName") == vec!["First", "Name"]);
+        assert!(words("HTTPServer") == vec!["HTTPServer"]);
+        assert!(words("value2Go") == vec!["value2", "Go"]);
+        assert!(words("a__b") == vec!["a", "b"]);
+    }
+
+    #[test]
+    fn apply_rules() {
+        let rule = |name: &str| RenameRule::from_name(name).unwrap();
+
+        assert!(rule("lowercase").apply("first_name") == "firstname");
+        assert!(rule("UPPERCASE").apply("first_name") == "FIRSTNAME");
+        assert!(rule("PascalCase").apply("first_name") == "FirstName");
+        assert!(rule("camelCase").apply("first_name") == "firstName");
+        assert!(rule("camelCase").apply("get_first_name") == "getFirstName");
+        assert!(rule("snake_case").apply("FirstName") == "first_name");
+        assert!(rule("SCREAMING_SNAKE_CASE").apply("first_name") == "FIRST_NAME");
+        assert!(rule("snake_case").apply("_marker") == "_marker");
+        assert!(rule("PascalCase").apply("_private_thing") == "_PrivateThing");
+        assert!(RenameRule::from_name("kebab-case").is_none());
+    }
+}
@@ -8,148 +8,787 @@ use proc_macro2::{TokenStream, Span};
 use quote::quote;
 use syn::{
     DeriveInput,
-    FieldsNamed,
+    DataStruct,
+    Member,
     Type,
     Ident,
     Result,
     Error,
-    TypeTuple,
     AttrStyle,
     LitStr,
+    LitInt,
+    Generics,
+    TypePath,
+    TypeParen,
+    TypeGroup,
+    TypeReference,
+    PathSegment,
+    PathArguments,
+    GenericArgument,
+    TypeParamBound,
+    TraitBound,
+    TraitBoundModifier,
+    WherePredicate,
+    PredicateType,
     Attribute,
-    token::Paren,
+    Visibility,
+    Lifetime,
     punctuated::Punctuated,
     parse::{Parse, ParseStream},
 };
 
 use crate::{
-    extract::{named_fields, named_struct},
+    extract::{
+        named_struct,
+        field_members,
+        is_marker,
+        member_name,
+        end_of_option,
+        cfg_attributes,
+        cfg_gate,
+        parts_struct,
+        parts_member,
+        with_lifetime,
+        Vis,
+    },
     faultmsg::Problem,
 };
 
 pub struct Field {
     ty: Type,    
-    name: Ident,
+    member: Member,
+    cfgs: Vec<Attribute>,
 }
 
 impl Field {
-    fn from_field(field: &syn::Field) -> Result<Self> {
-        let name: Ident =  field.ident
-            .clone()
-            .ok_or(Error::new(Span::call_site(), Problem::UnnamedField))?;
-        
+    fn from_field(member: Member, field: &syn::Field) -> Result<Self> {
         Ok(Field {
             ty: field.ty.clone(),
-            name: name,
+            member,
+            cfgs: cfg_attributes(field.attrs.as_slice()),
         })
     }
     
-    fn from_fields_named(fields_named: &FieldsNamed) -> Result<Vec<Self>> {
-        fields_named.named
-            .iter()
-            .map(|field| Field::from_field(field))
+    fn from_fields(structure: &DataStruct, name: &str) -> Result<Vec<Self>> {
+        selected_fields(structure, name)?
+            .into_iter()
+            .map(|(member, field)| Field::from_field(member, field))
             .collect()
     }
 }
 
-struct Rename {
-    name: Ident,
+/// Whether a field's type may be unsized, so can't be moved out of the struct. That's `str`,
+/// slices, trait objects and type parameters relaxed with `?Sized`.
+fn is_unsized(ty: &Type, generics: &Generics) -> bool {
+    let relaxed = |bounds: &Punctuated<TypeParamBound, syn::Token![+]>| bounds
+        .iter()
+        .any(|bound| matches!(
+            bound,
+            TypeParamBound::Trait(TraitBound { modifier: TraitBoundModifier::Maybe(_), .. })
+        ));
+
+    match ty {
+        Type::Slice(_) | Type::TraitObject(_) => true,
+        Type::Paren(TypeParen { elem, .. }) | Type::Group(TypeGroup { elem, .. }) => {
+            is_unsized(elem, generics)
+        },
+        Type::Path(TypePath { qself: None, path }) => match path.get_ident() {
+            Some(ident) if ident == "str" => true,
+            Some(ident) => {
+                generics.type_params().any(|param| param.ident == *ident && relaxed(&param.bounds))
+                    || generics.where_clause
+                        .iter()
+                        .flat_map(|clause| clause.predicates.iter())
+                        .any(|predicate| matches!(
+                            predicate,
+                            WherePredicate::Type(PredicateType { bounded_ty, bounds, .. })
+                                if is_param(bounded_ty, ident) && relaxed(bounds)
+                        ))
+            },
+            None => false,
+        },
+        _ => false,
+    }
+}
+
+/// True when `ty` is a type parameter, or one wrapped in references, `Box` or `Pin`. These
+/// are fundamental types, so the parameter is still uncovered for the orphan rules.
+fn is_uncovered_param(ty: &Type, generics: &Generics) -> bool {
+    match ty {
+        Type::Paren(TypeParen { elem, .. })
+        | Type::Group(TypeGroup { elem, .. })
+        | Type::Reference(TypeReference { elem, .. }) => is_uncovered_param(elem, generics),
+        Type::Path(TypePath { qself: None, path }) => match path.get_ident() {
+            Some(ident) => generics.type_params().any(|param| param.ident == *ident),
+            None => match path.segments.last() {
+                Some(PathSegment { ident, arguments: PathArguments::AngleBracketed(args) })
+                    if ident == "Box" || ident == "Pin" =>
+                {
+                    matches!(
+                        args.args.first(),
+                        Some(GenericArgument::Type(elem)) if is_uncovered_param(elem, generics)
+                    )
+                },
+                _ => false,
+            },
+        },
+        _ => false,
+    }
+}
+
+fn is_param(ty: &Type, ident: &Ident) -> bool {
+    matches!(ty, Type::Path(TypePath { qself: None, path }) if path.is_ident(ident))
+}
+
+/// Field options, set with the same attribute as the struct options of each derive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldAction {
+    Skip,
+    Include,
+    Position(LitInt),
+}
+
+impl Parse for FieldAction {
+    fn parse(input: ParseStream) -> Result<Self> {
+        syn::custom_keyword!(skip);
+        syn::custom_keyword!(include);
+        syn::custom_keyword!(position);
+
+        if input.peek(skip) {
+            let _ = input.parse::<skip>()?;
+            if !end_of_option(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowSkip))
+            } else {
+                Ok(FieldAction::Skip)
+            }
+        } else if input.peek(include) {
+            let _ = input.parse::<include>()?;
+            Ok(FieldAction::Include)
+        } else if input.peek(position) {
+            let _ = input.parse::<position>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            Ok(FieldAction::Position(input.parse()?))
+        } else {
+            Err(Error::new(Span::call_site(), Problem::InvalidAttribute))
+        }
+    }
+}
+
+fn field_actions_from(attributes: &[Attribute], name: &str) -> Result<Vec<FieldAction>> {
+    let mut current: Vec<FieldAction> = Vec::new();
+
+    for attr in attributes {
+        if attr.style != AttrStyle::Outer { continue; }
+
+        if attr.path.is_ident(name) {
+            let actions = attr.parse_args_with(
+                Punctuated::<FieldAction, syn::Token![,]>::parse_terminated
+            )?;
+            current.extend(actions);
+        }
+    }
+
+    Ok(current)
+}
+
+/// The fields a dissolve method hands back, in order. Skipped fields are left out, as are
+/// markers like `PhantomData` unless included. Fields given a `position` are put there and
+/// the rest fill the gaps in the order they were declared.
+pub fn selected_fields<'a>(
+    structure: &'a DataStruct,
+    name: &str,
+) -> Result<Vec<(Member, &'a syn::Field)>> {
+    let mut positioned = Vec::new();
+    let mut unpositioned = Vec::new();
+
+    for (member, field) in field_members(&structure.fields) {
+        let mut skip = is_marker(&field.ty);
+        let mut position = None;
+        for action in field_actions_from(field.attrs.as_slice(), name)? {
+            match action {
+                FieldAction::Skip => skip = true,
+                FieldAction::Include => skip = false,
+                FieldAction::Position(index) => position = Some(index),
+            }
+        }
+
+        match (skip, position) {
+            (true, _) => (),
+            (false, Some(index)) => positioned.push((index, (member, field))),
+            (false, None) => unpositioned.push((member, field)),
+        }
+    }
+
+    let count = positioned.len() + unpositioned.len();
+    let mut slots: Vec<Option<(Member, &syn::Field)>> = (0..count).map(|_| None).collect();
+    for (index, selected) in positioned {
+        let position = index.base10_parse::<usize>()?;
+        match slots.get_mut(position) {
+            None => return Err(
+                Error::new_spanned(index, Problem::PositionOutOfRange(position, count))
+            ),
+            Some(Some(_)) => return Err(
+                Error::new_spanned(index, Problem::DuplicatePosition(position))
+            ),
+            Some(slot) => *slot = Some(selected),
+        }
+    }
+
+    let mut unpositioned = unpositioned.into_iter();
+    Ok(slots
+        .into_iter()
+        .filter_map(|slot| slot.or_else(|| unpositioned.next()))
+        .collect())
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Action {
+    Rename(Ident),
+    Vis(Vis),
+    ImplFrom,
+    Parts(Ident),
+    Moves(Moves),
+    Shape(ShapeAction),
 }
 
-impl Parse for Rename {
+impl Parse for Action {
     fn parse(input: ParseStream) -> Result<Self> {
         syn::custom_keyword!(rename);
+        syn::custom_keyword!(vis);
+        syn::custom_keyword!(impl_from);
+        syn::custom_keyword!(parts);
+        syn::custom_keyword!(skip_drop);
+        syn::custom_keyword!(take);
 
         if input.peek(rename) {
             let _ = input.parse::<rename>()?;
             let _ = input.parse::<syn::Token![=]>()?;
             let name = input.parse::<LitStr>()?;
-            if !input.is_empty() {
+            if !end_of_option(input) {
                 Err(Error::new(Span::call_site(), Problem::TokensFollowNewName))
             } else {
                 let name = Ident::new(name.value().as_str(), Span::call_site());
-                Ok(Rename { name } )
+                Ok(Action::Rename(name))
             }
+        } else if input.peek(vis) {
+            Ok(Action::Vis(input.parse()?))
+        } else if input.peek(impl_from) {
+            let _ = input.parse::<impl_from>()?;
+            Ok(Action::ImplFrom)
+        } else if input.peek(parts) {
+            let _ = input.parse::<parts>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let name = input.parse::<LitStr>()?;
+            if !end_of_option(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowNewName))
+            } else {
+                Ok(Action::Parts(name.parse()?))
+            }
+        } else if input.peek(skip_drop) {
+            let _ = input.parse::<skip_drop>()?;
+            Ok(Action::Moves(Moves::SkipDrop))
+        } else if input.peek(take) {
+            let _ = input.parse::<take>()?;
+            Ok(Action::Moves(Moves::Take))
+        } else if let Some(shape) = parse_shape(input)? {
+            Ok(Action::Shape(shape))
         } else {
             Err(Error::new(Span::call_site(), Problem::InvalidAttribute))
         }
     }
 }
 
-fn dissolve_rename_from(attributes: &[Attribute]) -> Result<Option<Ident>> {
-    let mut current: Option<Ident> = None;
+/// Tuple options shared by the dissolve derives.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShapeAction {
+    UnwrapSingle,
+    Chunk(LitInt),
+}
+
+pub fn parse_shape(input: ParseStream) -> Result<Option<ShapeAction>> {
+    syn::custom_keyword!(unwrap_single);
+    syn::custom_keyword!(chunk);
+
+    if input.peek(unwrap_single) {
+        let _ = input.parse::<unwrap_single>()?;
+        Ok(Some(ShapeAction::UnwrapSingle))
+    } else if input.peek(chunk) {
+        let _ = input.parse::<chunk>()?;
+        let _ = input.parse::<syn::Token![=]>()?;
+        Ok(Some(ShapeAction::Chunk(input.parse()?)))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Shape of the tuple returned by a dissolve method. A single field is returned as a
+/// 1-tuple, `(T,)`, unless `unwrap_single` is set. With `chunk` a tuple with more than that
+/// many elements is split into nested tuples of at most `chunk` elements, so large structs
+/// still give tuples implementing `Debug`, `PartialEq` and the like.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Shape {
+    unwrap_single: bool,
+    chunk: Option<usize>,
+}
+
+impl Shape {
+    pub fn apply(&mut self, action: ShapeAction) -> Result<()> {
+        match action {
+            ShapeAction::UnwrapSingle => self.unwrap_single = true,
+            ShapeAction::Chunk(size) => {
+                let chunk = size.base10_parse::<usize>()?;
+                if chunk < 2 {
+                    return Err(Error::new_spanned(size, Problem::ChunkTooSmall));
+                }
+                self.chunk = Some(chunk);
+            },
+        }
+
+        Ok(())
+    }
+
+    /// True when a single field is returned as it is rather than in a tuple.
+    pub fn unwraps(&self, count: usize) -> bool {
+        self.unwrap_single && count == 1
+    }
+
+    /// Checks `From` can be implemented for the tuple of `types` as returned. It can't be
+    /// for a bare type parameter, which a single field may unwrap to.
+    pub fn check_impl_from(&self, types: &[&Type], generics: &Generics) -> Result<()> {
+        match types {
+            [ty] if self.unwraps(1) && is_uncovered_param(ty, generics) => {
+                Err(Error::new_spanned(ty, Problem::ImplFromTypeParam))
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// Groups `elements` into chunks, and those chunks into further chunks, until there
+    /// are few enough for one tuple.
+    fn nest<T>(&self, elements: Vec<T>, group: impl Fn(Vec<T>) -> T) -> Vec<T> {
+        let mut elements = elements;
+        if let Some(chunk) = self.chunk {
+            while elements.len() > chunk {
+                let mut ungrouped = elements.into_iter().peekable();
+                let mut grouped = Vec::new();
+                while ungrouped.peek().is_some() {
+                    grouped.push(group(ungrouped.by_ref().take(chunk).collect()));
+                }
+                elements = grouped;
+            }
+        }
+
+        elements
+    }
+
+    /// Tuple of the given types or expressions.
+    pub fn tuple(&self, elements: Vec<TokenStream>) -> TokenStream {
+        let tuple = |elements: Vec<TokenStream>| quote!((#(#elements,)*));
+
+        if self.unwraps(elements.len()) {
+            return elements.into_iter().collect();
+        }
+
+        tuple(self.nest(elements, tuple))
+    }
+
+    /// The field names as they're laid out in the tuple, for documentation.
+    pub fn order(&self, names: Vec<String>) -> String {
+        let tuple = |names: Vec<String>| match names.len() {
+            1 => format!("({},)", names[0]),
+            _ => format!("({})", names.join(", ")),
+        };
+
+        if self.unwraps(names.len()) {
+            return names.concat();
+        }
+
+        tuple(self.nest(names, tuple))
+    }
+}
+
+/// How `dissolve` gets the fields out of the struct.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Moves {
+    /// Moving each field out, which isn't allowed for structs implementing `Drop`.
+    #[default]
+    Fields,
+    /// Reading each field out of the struct wrapped in `ManuallyDrop`, so its `Drop` impl
+    /// never runs. Fields left out of the tuple are dropped in place.
+    SkipDrop,
+    /// Swapping each field for its default with `mem::take`, after which the struct is
+    /// dropped as usual.
+    Take,
+}
+
+fn dissolve_actions_from(attributes: &[Attribute], name: &str) -> Result<Vec<Action>> {
+    let mut current: Vec<Action> = Vec::new();
 
     for attr in attributes {
         if attr.style != AttrStyle::Outer { continue; }
 
-        if attr.path.is_ident("dissolve") {
-            let rename = attr.parse_args::<Rename>()?;
-            current = Some(rename.name);
+        if attr.path.is_ident(name) {
+            let actions = attr.parse_args_with(
+                Punctuated::<Action, syn::Token![,]>::parse_terminated
+            )?;
+            current.extend(actions);
         }
     }
 
     Ok(current)
 }
 
-pub struct NamedStruct<'a> {
+/// How `dissolve` returns the fields of `node`, as the parts struct if it has one or else a
+/// tuple of the given shape. `New` reads this to implement `From` for the same thing.
+pub fn returned_as(node: &DeriveInput) -> Result<(Shape, Option<Ident>)> {
+    let mut parts = None;
+    let mut shape = Shape::default();
+    for action in dissolve_actions_from(node.attrs.as_slice(), "dissolve")? {
+        match action {
+            Action::Parts(name) => parts = Some(name),
+            Action::Shape(action) => shape.apply(action)?,
+            _ => (),
+        }
+    }
+
+    Ok((shape, parts))
+}
+
+/// Which of the dissolve derives a method is for, and so whether it moves the fields out
+/// or borrows them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Move,
+    Ref,
+    Mut,
+}
+
+impl Kind {
+    /// Name of the attribute holding the options, which is also the default method name.
+    fn attribute(self) -> &'static str {
+        match self {
+            Kind::Move => "dissolve",
+            Kind::Ref => "dissolve_ref",
+            Kind::Mut => "dissolve_mut",
+        }
+    }
+
+    /// Lifetime of the references handed back, if any.
+    fn lifetime(self, name: &str) -> Option<Lifetime> {
+        match self {
+            Kind::Move => None,
+            Kind::Ref | Kind::Mut => Some(Lifetime::new(name, Span::call_site())),
+        }
+    }
+
+    /// The type a field of type `ty` is handed back as.
+    fn returned(self, ty: &Type, lifetime: Option<&Lifetime>) -> Type {
+        match self {
+            Kind::Move => ty.clone(),
+            Kind::Ref => syn::parse_quote!(&#lifetime #ty),
+            Kind::Mut => syn::parse_quote!(&#lifetime mut #ty),
+        }
+    }
+
+    /// Documentation templates for the method returning one field, a tuple or a parts
+    /// struct, and for each field of a parts struct.
+    fn wording(self) -> [&'static str; 4] {
+        match self {
+            Kind::Move => [
+                "Consumes `{struct}` returning its `{field}` field.",
+                "Consumes `{struct}` returning a tuple of its fields in the order `{order}`.",
+                "Consumes `{struct}` returning its fields as a [`{parts}`].",
+                "The `{field}` field.",
+            ],
+            Kind::Ref => [
+                "Returns a reference to the `{field}` field of `{struct}`.",
+                "Returns a tuple of references to the fields of `{struct}` in the order \
+                 `{order}`.",
+                "Returns references to the fields of `{struct}` as a [`{parts}`].",
+                "Reference to the `{field}` field.",
+            ],
+            Kind::Mut => [
+                "Returns a mutable reference to the `{field}` field of `{struct}`.",
+                "Returns a tuple of mutable references to the fields of `{struct}` in the \
+                 order `{order}`.",
+                "Returns mutable references to the fields of `{struct}` as a [`{parts}`].",
+                "Mutable reference to the `{field}` field.",
+            ],
+        }
+    }
+}
+
+/// A dissolve method along with the parts struct and `From` impl it may come with, as
+/// generated by each of `Dissolve`, `DissolveRef` and `DissolveMut`.
+pub struct Method<'a> {
     original: &'a DeriveInput,
-    name: Ident,
+    kind: Kind,
     fields: Vec<Field>,
-    dissolve_rename: Option<Ident>,
+    fn_name: Ident,
+    vis: Visibility,
+    cfg: TokenStream,
+    impl_from: bool,
+    parts: Option<Ident>,
+    moves: Moves,
+    shape: Shape,
 }
 
-impl<'a> NamedStruct<'a> {
-    pub fn emit(&self) -> TokenStream {
-        let (impl_generics, struct_generics, where_clause) = self.original.generics
-            .split_for_impl();        
-        let struct_name = &self.name;
+impl<'a> Method<'a> {
+    pub fn from_node(node: &'a DeriveInput, kind: Kind) -> Result<Self> {
+        let struct_data = named_struct(node)?;
+        let fields = Field::from_fields(struct_data, kind.attribute())?;
 
-        let types: Punctuated<Type, syn::Token![,]> = self.fields
+        // A field that is configured out takes its tuple element with it, so the method
+        // only exists when every field does.
+        let cfgs: Vec<Attribute> = fields
             .iter()
-            .fold(Punctuated::new(), |mut p, field| {
-                p.push(field.ty.clone());
-                p
-            });
-
-        let type_tuple = TypeTuple {
-            paren_token: Paren { span: Span::call_site() },
-            elems: types,
+            .flat_map(|field| field.cfgs.iter().cloned())
+            .collect();
+        let cfg = cfg_gate(cfgs.as_slice())?;
+
+        let mut rename = None;
+        let mut vis = Vis::default();
+        let mut impl_from = false;
+        let mut parts = None;
+        let mut moves = Moves::default();
+        let mut shape = Shape::default();
+        for action in dissolve_actions_from(node.attrs.as_slice(), kind.attribute())? {
+            match action {
+                Action::Rename(name) => rename = Some(name),
+                Action::Vis(v) => vis = v,
+                Action::ImplFrom => impl_from = true,
+                Action::Parts(name) => parts = Some(name),
+                Action::Moves(m) if kind == Kind::Move => moves = m,
+                Action::Moves(_) => {
+                    return Err(Error::new(Span::call_site(), Problem::InvalidAttribute));
+                },
+                Action::Shape(action) => shape.apply(action)?,
+            }
+        }
+        if impl_from && parts.is_none() {
+            let types: Vec<&Type> = fields.iter().map(|field| &field.ty).collect();
+            shape.check_impl_from(types.as_slice(), &node.generics)?;
+        }
+
+        Ok(Method {
+            original: node,
+            kind,
+            fields,
+            fn_name: rename.unwrap_or_else(|| Ident::new(kind.attribute(), Span::call_site())),
+            vis: vis.resolve(&node.vis),
+            cfg,
+            impl_from,
+            parts,
+            moves,
+            shape,
+        })
+    }
+
+    /// The method taking `receiver` and handing back `values`, one for each field, in a
+    /// body that `wrap` may add to.
+    pub fn emit(
+        &self,
+        receiver: TokenStream,
+        values: Vec<TokenStream>,
+        wrap: impl FnOnce(TokenStream) -> TokenStream,
+    ) -> TokenStream {
+        let generics = &self.original.generics;
+        let (impl_generics, struct_generics, where_clause) = generics.split_for_impl();
+        let struct_name = &self.original.ident;
+        let vis = &self.vis;
+        let fn_name = &self.fn_name;
+        let [single_doc, tuple_doc, parts_doc, part_doc] = self.kind.wording();
+
+        // References are handed back with the lifetime of `self`, which is elided in the
+        // method and named in the `From` impl.
+        let lifetime = self.kind.lifetime("'__dissolve");
+        let returned = |lifetime: Option<&Lifetime>| -> Vec<Type> {
+            self.fields
+                .iter()
+                .map(|field| self.kind.returned(&field.ty, lifetime))
+                .collect()
+        };
+        let with_generics = |lifetime: Option<Lifetime>| match lifetime {
+            Some(lifetime) => {
+                let generics = with_lifetime(generics, &lifetime);
+                let (_, type_generics, _) = generics.split_for_impl();
+                quote!(#type_generics)
+            },
+            None => quote!(#struct_generics),
         };
 
-        let fields: TokenStream = self.fields
-            .iter()
-            .enumerate()
-            .fold(TokenStream::new(), |mut ts, (count, field)| {
-                if count > 0 {
-                    ts.extend(quote!(,))
-                }
-                
-                let field_name = &field.name;
-                let field_expr = quote!(
-                    self.#field_name
+        let (returns, borrowed, body, doc, cfg, parts) = match self.parts {
+            Some(ref parts_name) => {
+                let types = returned(lifetime.as_ref());
+                let fields: Vec<(&Member, Type, &[Attribute])> = self.fields
+                    .iter()
+                    .zip(types)
+                    .map(|(field, ty)| (&field.member, ty, field.cfgs.as_slice()))
+                    .collect();
+                let (declaration, marker) = parts_struct(
+                    self.original,
+                    parts_name,
+                    vis,
+                    fields.as_slice(),
+                    lifetime.as_ref(),
+                    part_doc,
                 );
+                let initialisers = self.fields
+                    .iter()
+                    .zip(values)
+                    .enumerate()
+                    .map(|(index, (Field { member, cfgs, .. }, value))| {
+                        let part = parts_member(member, index);
+                        quote!(#(#cfgs)* #part: #value)
+                    })
+                    .chain(marker);
+                let doc = parts_doc
+                    .replace("{struct}", struct_name.to_string().as_str())
+                    .replace("{parts}", parts_name.to_string().as_str());
+                let elided = with_generics(self.kind.lifetime("'_"));
+                let named = with_generics(lifetime.clone());
+
+                // The parts struct carries the `cfg` of each field so there's no need to
+                // gate the method.
+                (
+                    quote!(#parts_name #elided),
+                    quote!(#parts_name #named),
+                    quote!(#parts_name { #(#initialisers),* }),
+                    doc,
+                    TokenStream::new(),
+                    declaration,
+                )
+            },
+            None => {
+                let names: Vec<String> = self.fields
+                    .iter()
+                    .map(|field| member_name(&field.member))
+                    .collect();
+                let doc = if self.shape.unwraps(names.len()) {
+                    single_doc.replace("{field}", names[0].as_str())
+                } else {
+                    tuple_doc.replace("{order}", self.shape.order(names).as_str())
+                };
+                let tuple = |types: Vec<Type>| {
+                    self.shape.tuple(types.iter().map(|ty| quote!(#ty)).collect())
+                };
 
-                ts.extend(field_expr);
+                (
+                    tuple(returned(None)),
+                    tuple(returned(lifetime.as_ref())),
+                    self.shape.tuple(values),
+                    doc.replace("{struct}", struct_name.to_string().as_str()),
+                    self.cfg.clone(),
+                    TokenStream::new(),
+                )
+            },
+        };
+        let body = wrap(body);
 
-                ts
-            });
+        let impl_from = if self.impl_from {
+            let from_generics = match lifetime {
+                Some(ref lifetime) => with_lifetime(generics, lifetime),
+                None => generics.clone(),
+            };
+            let (from_impl_generics, _, _) = from_generics.split_for_impl();
+            let source = match self.kind {
+                Kind::Move => quote!(#struct_name #struct_generics),
+                Kind::Ref => quote!(&#lifetime #struct_name #struct_generics),
+                Kind::Mut => quote!(&#lifetime mut #struct_name #struct_generics),
+            };
+            quote!(
+                #cfg
+                impl #from_impl_generics ::std::convert::From<#source> for #borrowed
+                    #where_clause
+                {
+                    fn from(value: #source) -> Self {
+                        value.#fn_name()
+                    }
+                }
+            )
+        } else {
+            TokenStream::new()
+        };
 
-        let dissolve = Ident::new("dissolve", Span::call_site());
-        let fn_name = self.dissolve_rename
-            .as_ref()
-            .unwrap_or(&dissolve);
-        
         quote!(
             impl #impl_generics #struct_name #struct_generics
                 #where_clause
             {
-                pub fn #fn_name(self) -> #type_tuple {
-                    (
-                        #fields
-                    )
+                #cfg
+                #[doc = #doc]
+                #vis fn #fn_name(#receiver) -> #returns {
+                    #body
                 }
             }
-        )        
+
+            #parts
+
+            #impl_from
+        )
+    }
+
+    /// The method of `DissolveRef` or `DissolveMut`, borrowing each field from `self`.
+    pub fn emit_borrowed(&self) -> TokenStream {
+        let (receiver, reference) = match self.kind {
+            Kind::Mut => (quote!(&mut self), quote!(&mut)),
+            Kind::Ref | Kind::Move => (quote!(&self), quote!(&)),
+        };
+        let values = self.fields
+            .iter()
+            .map(|Field { member, .. }| quote!(#reference self.#member))
+            .collect();
+
+        self.emit(receiver, values, |body| body)
+    }
+}
+
+pub struct NamedStruct<'a> {
+    method: Method<'a>,
+    dropped: Vec<Field>,
+}
+
+impl<'a> NamedStruct<'a> {
+    pub fn emit(&self) -> TokenStream {
+        let fields = &self.method.fields;
+
+        let this = Ident::new("this", Span::mixed_site());
+        let values = fields
+            .iter()
+            .map(|Field { member, .. }| match self.method.moves {
+                Moves::Fields => quote!(self.#member),
+                Moves::SkipDrop => quote!(::std::ptr::read(&#this.#member)),
+                Moves::Take => quote!(::std::mem::take(&mut self.#member)),
+            })
+            .collect();
+
+        match self.method.moves {
+            Moves::Fields => self.method.emit(quote!(self), values, |body| body),
+            Moves::Take => self.method.emit(quote!(mut self), values, |body| body),
+            Moves::SkipDrop => {
+                let drops = self.dropped.iter().map(|Field { member, cfgs, .. }| quote!(
+                    #(#cfgs)* ::std::ptr::drop_in_place(&mut #this.#member);
+                ));
+
+                // Dropped fields behind a `cfg` may all be configured out.
+                let binding = if self.dropped.is_empty() {
+                    quote!(let)
+                } else if self.dropped.iter().all(|field| !field.cfgs.is_empty()) {
+                    quote!(#[allow(unused_mut)] let mut)
+                } else {
+                    quote!(let mut)
+                };
+
+                // Every field is either read out once or dropped in place once, and the
+                // struct itself is never dropped, so nothing is used after being moved.
+                self.method.emit(quote!(self), values, |body| quote!(
+                    #binding #this = ::std::mem::ManuallyDrop::new(self);
+                    unsafe {
+                        #(#drops)*
+                        #body
+                    }
+                ))
+            },
+        }
     }
 }
 
@@ -158,15 +797,143 @@ impl<'a> TryFrom<&'a DeriveInput> for NamedStruct<'a> {
     
     fn try_from(node: &'a DeriveInput) -> Result<Self> {
         let struct_data = named_struct(node)?;
-        let named_fields = named_fields(struct_data)?;
-        let fields = Field::from_fields_named(named_fields)?;
-        let rename = dissolve_rename_from(node.attrs.as_slice())?;
+        if let Some(field) = struct_data.fields
+            .iter()
+            .find(|field| is_unsized(&field.ty, &node.generics))
+        {
+            return Err(Error::new_spanned(&field.ty, Problem::UnsizedField));
+        }
 
-        Ok(NamedStruct {
-            original: node,
-            name: node.ident.clone(),
-            fields,
-            dissolve_rename: rename,
-        })
+        let method = Method::from_node(node, Kind::Move)?;
+        let dropped = field_members(&struct_data.fields)
+            .into_iter()
+            .filter(|(member, _)| !method.fields.iter().any(|field| field.member == *member))
+            .map(|(member, field)| Field::from_field(member, field))
+            .collect::<Result<Vec<Field>>>()?;
+
+        Ok(NamedStruct { method, dropped })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn order(node: &DeriveInput) -> Result<Vec<String>> {
+        let fields = selected_fields(named_struct(node)?, "dissolve")?;
+        Ok(fields.iter().map(|(member, _)| member_name(member)).collect())
+    }
+
+    #[test]
+    fn select_fields() -> Result<()> {
+        let node: DeriveInput = syn::parse_quote!(
+            struct Order {
+                id: u64,
+                #[dissolve(skip)]
+                cache: Vec<u8>,
+                #[dissolve(position = 0)]
+                total: f64,
+                lines: Vec<String>,
+            }
+        );
+        assert!(order(&node)? == vec!["total", "id", "lines"]);
+
+        let node: DeriveInput = syn::parse_quote!(
+            struct Order {
+                #[dissolve(position = 1)]
+                id: u64,
+                #[dissolve(position = 1)]
+                total: f64,
+            }
+        );
+        assert!(order(&node).is_err());
+
+        let node: DeriveInput = syn::parse_quote!(
+            struct Order {
+                #[dissolve(position = 2)]
+                id: u64,
+                total: f64,
+            }
+        );
+        assert!(order(&node).is_err());
+
+        let node: DeriveInput = syn::parse_quote!(
+            struct Pinned<T> {
+                marker: PhantomData<T>,
+                #[dissolve(include)]
+                pinned: ::std::marker::PhantomPinned,
+                value: T,
+            }
+        );
+        assert!(order(&node)? == vec!["pinned", "value"]);
+
+        let r: Result<FieldAction> = syn::parse_str("position = \"1\"");
+        assert!(r.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn tuple_shape() -> Result<()> {
+        let names = |count: usize| (0..count).map(|i| i.to_string()).collect::<Vec<String>>();
+
+        let shape = Shape::default();
+        assert!(shape.order(names(1)) == "(0,)");
+        assert!(shape.order(names(3)) == "(0, 1, 2)");
+        assert!(shape.order(names(0)) == "()");
+
+        let mut shape = Shape::default();
+        shape.apply(ShapeAction::UnwrapSingle)?;
+        assert!(shape.order(names(1)) == "0");
+        assert!(shape.order(names(2)) == "(0, 1)");
+
+        let mut shape = Shape::default();
+        shape.apply(ShapeAction::Chunk(syn::parse_quote!(2)))?;
+        assert!(shape.order(names(2)) == "(0, 1)");
+        assert!(shape.order(names(5)) == "(((0, 1), (2, 3)), ((4,),))");
+
+        let mut shape = Shape::default();
+        assert!(shape.apply(ShapeAction::Chunk(syn::parse_quote!(1))).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn unsized_fields() {
+        let generics: Generics = syn::parse_quote!(<T: ?Sized, U, V>);
+        let mut generics = generics;
+        generics.where_clause = Some(syn::parse_quote!(where V: ?Sized + Send));
+
+        assert!(is_unsized(&syn::parse_quote!(str), &generics));
+        assert!(is_unsized(&syn::parse_quote!([u8]), &generics));
+        assert!(is_unsized(&syn::parse_quote!(dyn std::fmt::Debug), &generics));
+        assert!(is_unsized(&syn::parse_quote!(T), &generics));
+        assert!(is_unsized(&syn::parse_quote!(V), &generics));
+        assert!(!is_unsized(&syn::parse_quote!(U), &generics));
+        assert!(!is_unsized(&syn::parse_quote!(&str), &generics));
+        assert!(!is_unsized(&syn::parse_quote!(Box<T>), &generics));
+        assert!(!is_unsized(&syn::parse_quote!([u8; 4]), &generics));
+    }
+
+    #[test]
+    fn impl_from_unwrapped() -> Result<()> {
+        let generics: Generics = syn::parse_quote!(<'a, T>);
+        let param: Type = syn::parse_quote!(T);
+        let reference: Type = syn::parse_quote!(&'a T);
+        let boxed: Type = syn::parse_quote!(Box<T>);
+        let covered: Type = syn::parse_quote!(Vec<T>);
+
+        let shape = Shape::default();
+        assert!(shape.check_impl_from(&[&param], &generics).is_ok());
+
+        let mut shape = Shape::default();
+        shape.apply(ShapeAction::UnwrapSingle)?;
+        assert!(shape.check_impl_from(&[&param], &generics).is_err());
+        assert!(shape.check_impl_from(&[&reference], &generics).is_err());
+        assert!(shape.check_impl_from(&[&boxed], &generics).is_err());
+        assert!(shape.check_impl_from(&[&covered], &generics).is_ok());
+        assert!(shape.check_impl_from(&[&param, &param], &generics).is_ok());
+
+        Ok(())
     }
 }
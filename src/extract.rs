@@ -1,20 +1,78 @@
 //! Common functions
 
-use proc_macro2::Span;
-use syn::{FieldsNamed, DataStruct, DeriveInput, Data, Fields, Error, Result};
+use proc_macro2::{TokenStream, TokenTree, Span};
+use quote::quote;
+use syn::{
+    DataStruct,
+    DataEnum,
+    DeriveInput,
+    Data,
+    Fields,
+    Field,
+    Type,
+    TypePath,
+    Ident,
+    Generics,
+    GenericParam,
+    Lifetime,
+    LifetimeDef,
+    Member,
+    Index,
+    Error,
+    Result,
+    LitStr,
+    Visibility,
+    Attribute,
+    AttrStyle,
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+};
 
 use crate::faultmsg::{StructIs, Problem};
 
-pub fn named_fields<'a>(structure: &'a DataStruct) -> Result<&'a FieldsNamed> {
-    match structure.fields {
-        Fields::Named(ref fields) => Ok(fields),
-        Fields::Unnamed(_) | Fields::Unit => Err(
-            Error::new(Span::call_site(), Problem::UnnamedField)
-        ),
+/// Every field of a struct, or enum variant, alongside how it's reached. Tuple fields are
+/// reached by their index and unit structs have no fields at all.
+pub fn field_members(fields: &Fields) -> Vec<(Member, &Field)> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let member = match field.ident {
+                Some(ref ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(Index { index: index as u32, span: Span::call_site() }),
+            };
+            (member, field)
+        })
+        .collect()
+}
+
+/// True for `PhantomData` and `PhantomPinned` fields, going by the last path segment. They
+/// hold no data so are left out of generated methods unless asked for.
+pub fn is_marker(ty: &Type) -> bool {
+    match ty {
+        Type::Path(TypePath { qself: None, path }) => path.segments
+            .last()
+            .is_some_and(|segment| {
+                segment.ident == "PhantomData" || segment.ident == "PhantomPinned"
+            }),
+        _ => false,
+    }
+}
+
+/// True for a tuple struct of exactly one field.
+pub fn is_newtype(structure: &DataStruct) -> bool {
+    matches!(structure.fields, Fields::Unnamed(ref fields) if fields.unnamed.len() == 1)
+}
+
+/// The field name, or index for tuple structs, as written in the source.
+pub fn member_name(member: &Member) -> String {
+    match member {
+        Member::Named(ident) => ident.unraw().to_string(),
+        Member::Unnamed(index) => index.index.to_string(),
     }
 }
 
-pub fn named_struct<'a>(node: &'a DeriveInput) -> Result<&'a DataStruct> {
+pub fn named_struct(node: &DeriveInput) -> Result<&DataStruct> {
     match node.data {
         Data::Struct(ref structure) => Ok(structure),
         Data::Enum(_) => Err(
@@ -25,3 +83,239 @@ pub fn named_struct<'a>(node: &'a DeriveInput) -> Result<&'a DataStruct> {
         ),
     }
 }
+
+/// The `///` documentation attached to a field or item.
+pub fn doc_attributes(attributes: &[Attribute]) -> Vec<Attribute> {
+    attributes
+        .iter()
+        .filter(|attr| attr.style == AttrStyle::Outer && attr.path.is_ident("doc"))
+        .cloned()
+        .collect()
+}
+
+/// The `#[cfg(...)]` attributes on a field. The compiler has already expanded any
+/// `cfg_attr` and removed fields whose `cfg` is false by the time a derive runs, but the
+/// `cfg` attributes of the remaining fields are left in place for us to forward.
+pub fn cfg_attributes(attributes: &[Attribute]) -> Vec<Attribute> {
+    attributes
+        .iter()
+        .filter(|attr| attr.style == AttrStyle::Outer && attr.path.is_ident("cfg"))
+        .cloned()
+        .collect()
+}
+
+/// Combine the predicates of `cfg` attributes into one `#[cfg(all(...))]` gate. Gives an
+/// empty token stream if there are no attributes.
+pub fn cfg_gate(cfgs: &[Attribute]) -> Result<TokenStream> {
+    if cfgs.is_empty() {
+        return Ok(TokenStream::new());
+    }
+
+    let predicates = cfgs
+        .iter()
+        .map(|attr| attr.parse_args::<TokenStream>())
+        .collect::<Result<Vec<TokenStream>>>()?;
+
+    Ok(quote!(#[cfg(all(#(#predicates),*))]))
+}
+
+pub fn enum_data(node: &DeriveInput) -> Result<&DataEnum> {
+    match node.data {
+        Data::Enum(ref data) => Ok(data),
+        Data::Struct(_) => Err(
+            Error::new_spanned(node, Problem::NotEnum(StructIs::Struct))
+        ),
+        Data::Union(_) => Err(
+            Error::new_spanned(node, Problem::NotEnum(StructIs::Union))
+        ),
+    }
+}
+
+/// The generics of a type with one more lifetime in front, for impls over references to
+/// the type.
+pub fn with_lifetime(generics: &Generics, lifetime: &Lifetime) -> Generics {
+    let mut generics = generics.clone();
+    generics.params.insert(0, GenericParam::Lifetime(LifetimeDef::new(lifetime.clone())));
+    generics
+}
+
+/// Member of a parts struct for the field dissolved at `index`. Tuple fields are numbered
+/// afresh since skipped or repositioned fields no longer line up with the original struct.
+pub fn parts_member(member: &Member, index: usize) -> Member {
+    match member {
+        Member::Named(_) => member.clone(),
+        Member::Unnamed(_) => {
+            Member::Unnamed(Index { index: index as u32, span: Span::call_site() })
+        },
+    }
+}
+
+/// Declaration of a struct named `name` holding the given fields, each `pub`, in place of
+/// the tuple returned by a dissolve method. It has the generics of the original struct,
+/// with `lifetime` added in front when the fields are references. `field_doc` documents
+/// each field, with `{field}` replaced by its name.
+///
+/// Generic parameters that no field uses, say when the only field using one is skipped,
+/// are covered by a hidden `PhantomData` field. Its initialiser is returned alongside the
+/// declaration for the dissolve method to add to the others.
+pub fn parts_struct(
+    node: &DeriveInput,
+    name: &Ident,
+    vis: &Visibility,
+    fields: &[(&Member, Type, &[Attribute])],
+    lifetime: Option<&Lifetime>,
+    field_doc: &str,
+) -> (TokenStream, Option<TokenStream>) {
+    let generics = match lifetime {
+        Some(lifetime) => with_lifetime(&node.generics, lifetime),
+        None => node.generics.clone(),
+    };
+    let where_clause = &generics.where_clause;
+    let doc = format!("Fields of [`{}`].", node.ident);
+
+    let mut declarations: Vec<TokenStream> = fields
+        .iter()
+        .map(|(member, ty, cfgs)| {
+            let doc = field_doc.replace("{field}", member_name(member).as_str());
+            match member {
+                Member::Named(ident) => quote!(#(#cfgs)* #[doc = #doc] pub #ident: #ty),
+                Member::Unnamed(_) => quote!(#(#cfgs)* #[doc = #doc] pub #ty),
+            }
+        })
+        .collect();
+
+    let tuple = match fields.first() {
+        Some((member, _, _)) => matches!(member, Member::Unnamed(_)),
+        None => matches!(
+            &node.data,
+            Data::Struct(DataStruct { fields: Fields::Unnamed(_), .. })
+        ),
+    };
+
+    let marker = unused_params(&generics, fields).map(|ty| {
+        let (member, declaration) = if tuple {
+            let index = Index { index: fields.len() as u32, span: Span::call_site() };
+            (Member::Unnamed(index), quote!(#[doc(hidden)] pub #ty))
+        } else {
+            let ident = Ident::new("__dissolve_marker", Span::call_site());
+            (Member::Named(ident.clone()), quote!(#[doc(hidden)] pub #ident: #ty))
+        };
+        declarations.push(declaration);
+        quote!(#member: ::std::marker::PhantomData)
+    });
+
+    let declaration = if declarations.is_empty() {
+        quote!(
+            #[doc = #doc]
+            #vis struct #name #generics #where_clause;
+        )
+    } else if tuple {
+        quote!(
+            #[doc = #doc]
+            #vis struct #name #generics (#(#declarations),*) #where_clause;
+        )
+    } else {
+        quote!(
+            #[doc = #doc]
+            #vis struct #name #generics #where_clause {
+                #(#declarations),*
+            }
+        )
+    };
+
+    (declaration, marker)
+}
+
+/// `PhantomData` covering the lifetimes and type parameters in `generics` that none of
+/// `fields` use, or `None` if they're all used. Fields behind a `cfg` may not exist so
+/// they don't count as using anything.
+fn unused_params(
+    generics: &Generics,
+    fields: &[(&Member, Type, &[Attribute])],
+) -> Option<Type> {
+    let mut used = Vec::new();
+    for (_, ty, cfgs) in fields {
+        if cfgs.is_empty() {
+            mentioned(quote!(#ty), &mut used);
+        }
+    }
+
+    let unused: Vec<TokenStream> = generics.params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Lifetime(def) if !used.contains(&def.lifetime.to_string()) => {
+                let lifetime = &def.lifetime;
+                Some(quote!(&#lifetime ()))
+            },
+            GenericParam::Type(param) if !used.contains(&param.ident.to_string()) => {
+                let ident = &param.ident;
+                Some(quote!(::std::marker::PhantomData<#ident>))
+            },
+            _ => None,
+        })
+        .collect();
+
+    if unused.is_empty() {
+        None
+    } else {
+        Some(syn::parse_quote!(::std::marker::PhantomData<(#(#unused,)*)>))
+    }
+}
+
+/// Collects every identifier and lifetime in `tokens`, lifetimes with their leading `'`.
+fn mentioned(tokens: TokenStream, names: &mut Vec<String>) {
+    let mut tick = false;
+    for token in tokens {
+        let lifetime = tick;
+        tick = matches!(&token, TokenTree::Punct(punct) if punct.as_char() == '\'');
+        match token {
+            TokenTree::Group(group) => mentioned(group.stream(), names),
+            TokenTree::Ident(ident) if lifetime => names.push(format!("'{}", ident)),
+            TokenTree::Ident(ident) => names.push(ident.to_string()),
+            _ => (),
+        }
+    }
+}
+
+/// True when the current option in a comma separated attribute list has been fully parsed.
+pub fn end_of_option(input: ParseStream) -> bool {
+    input.is_empty() || input.peek(syn::Token![,])
+}
+
+/// Visibility of a generated method as set by a `vis = "..."` option.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Vis {
+    /// Copy the visibility of whatever the method is generated from.
+    Inherit,
+    Explicit(Visibility),
+}
+
+impl Vis {
+    pub fn resolve(&self, inherited: &Visibility) -> Visibility {
+        match self {
+            Vis::Inherit => inherited.clone(),
+            Vis::Explicit(vis) => vis.clone(),
+        }
+    }
+}
+
+impl Default for Vis {
+    fn default() -> Self {
+        Vis::Explicit(syn::parse_quote!(pub))
+    }
+}
+
+impl Parse for Vis {
+    fn parse(input: ParseStream) -> Result<Self> {
+        syn::custom_keyword!(vis);
+
+        let _ = input.parse::<vis>()?;
+        let _ = input.parse::<syn::Token![=]>()?;
+        let vis = input.parse::<LitStr>()?;
+        if vis.value() == "inherit" {
+            Ok(Vis::Inherit)
+        } else {
+            Ok(Vis::Explicit(vis.parse::<Visibility>()?))
+        }
+    }
+}
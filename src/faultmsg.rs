@@ -3,6 +3,7 @@ use std::fmt;
 
 #[derive(Debug)]
 pub enum StructIs {
+    Struct,
     Enum,
     Union,
 }
@@ -10,6 +11,7 @@ pub enum StructIs {
 impl fmt::Display for StructIs {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
+            Self::Struct => write!(f, "a struct"),
             Self::Enum => write!(f, "an enum"),
             Self::Union => write!(f, "a union"),
         }
@@ -21,19 +23,39 @@ impl fmt::Display for StructIs {
 #[derive(Debug)]
 pub enum Problem {
     NotNamedStruct(StructIs),
-    UnnamedField,
+    NotEnum(StructIs),
     TokensFollowSkip,
     TokensFollowNewName,
     InvalidAttribute,
+    UnknownRenameRule,
+    InvalidGetterName(String),
+    InvalidMethodName(String),
+    ConstNotPossible,
+    TraitOnlyWithoutTrait,
+    MutOnlyForUnions,
+    IntoInnerOnlyForNewtypes,
+    NotForMutGetters,
+    ValidateWithoutError,
+    NotForWith,
+    IntoNotForGetters,
+    DuplicatePosition(usize),
+    PositionOutOfRange(usize, usize),
+    UnsizedField,
+    ChunkTooSmall,
+    ImplFromTypeParam,
+    HomogeneousWithoutFields,
+    FieldTypeMismatch,
 }
 
 impl fmt::Display for Problem {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Self::NotNamedStruct(is) => {
-                write!(f, "type must be a named struct, not {}", is)
+                write!(f, "type must be a struct, not {}", is)
+            },
+            Self::NotEnum(is) => {
+                write!(f, "type must be an enum, not {}", is)
             },
-            Self::UnnamedField => write!(f, "struct fields must be named"),
             Self::TokensFollowSkip => {
                 write!(f, "tokens are not meant to follow skip attribute")
             },
@@ -43,6 +65,76 @@ impl fmt::Display for Problem {
             Self::InvalidAttribute => {
                 write!(f, "invalid attribute")
             },
+            Self::UnknownRenameRule => {
+                write!(
+                    f,
+                    "unknown rename rule, expected one of \"lowercase\", \"UPPERCASE\", \
+                     \"PascalCase\", \"camelCase\", \"snake_case\" or \
+                     \"SCREAMING_SNAKE_CASE\""
+                )
+            },
+            Self::InvalidGetterName(name) => {
+                write!(f, "`{}` is not a valid getter name", name)
+            },
+            Self::InvalidMethodName(name) => {
+                write!(f, "`{}` is not a valid method name", name)
+            },
+            Self::ConstNotPossible => {
+                write!(f, "getter cannot be const when it clones or derefs the field")
+            },
+            Self::TraitOnlyWithoutTrait => {
+                write!(f, "trait_only needs a trait to be given with trait or impl_trait")
+            },
+            Self::MutOnlyForUnions => {
+                write!(f, "mut getters can only be generated for unions")
+            },
+            Self::IntoInnerOnlyForNewtypes => {
+                write!(f, "into_inner can only be generated for newtypes")
+            },
+            Self::NotForMutGetters => {
+                write!(
+                    f,
+                    "copy, clone, deref, ref, const and into are not options for mutable getters"
+                )
+            },
+            Self::ValidateWithoutError => {
+                write!(f, "validate needs an error type to be given with error = \"Type\"")
+            },
+            Self::NotForWith => {
+                write!(f, "copy, clone, deref, ref and const are not options for with methods")
+            },
+            Self::IntoNotForGetters => {
+                write!(f, "into is not an option for getters")
+            },
+            Self::DuplicatePosition(position) => {
+                write!(f, "position {} is given to more than one field", position)
+            },
+            Self::PositionOutOfRange(position, count) => {
+                write!(f, "position {} is out of range for {} dissolved fields", position, count)
+            },
+            Self::UnsizedField => {
+                write!(
+                    f,
+                    "an unsized field can't be moved out by dissolve, DissolveRef or DissolveMut \
+                     can borrow it instead"
+                )
+            },
+            Self::ChunkTooSmall => {
+                write!(f, "chunk must be at least 2")
+            },
+            Self::ImplFromTypeParam => {
+                write!(
+                    f,
+                    "impl_from can't be combined with unwrap_single when the field is a type \
+                     parameter"
+                )
+            },
+            Self::HomogeneousWithoutFields => {
+                write!(f, "homogeneous structs need at least one field")
+            },
+            Self::FieldTypeMismatch => {
+                write!(f, "every field of a homogeneous struct must have the same type")
+            },
         }
     }
 }
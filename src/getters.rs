@@ -5,7 +5,13 @@ use proc_macro2::{TokenStream, Span};
 use quote::quote;
 use syn::{
     DeriveInput,
-    FieldsNamed,
+    Data,
+    DataStruct,
+    DataEnum,
+    DataUnion,
+    Fields,
+    Variant,
+    Member,
     Type,
     AttrStyle,
     Ident,
@@ -13,28 +19,122 @@ use syn::{
     Result,
     Error,
     Attribute,
+    Meta,
+    Path,
+    Visibility,
+    PathSegment,
+    PathArguments,
+    GenericArgument,
+    TypePath,
+    ext::IdentExt,
+    punctuated::Punctuated,
     parse::{Parse, ParseStream},
 };
 
 use crate::{
-    extract::{named_fields, named_struct},
+    case::RenameRule,
+    extract::{
+        field_members,
+        is_newtype,
+        is_marker,
+        member_name,
+        end_of_option,
+        doc_attributes,
+        cfg_attributes,
+        Vis,
+    },
     faultmsg::Problem,
 };
 
+/// How a getter hands back the field it's getting.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    #[default]
+    Ref,
+    Copy,
+    Clone,
+    Deref,
+}
+
+/// Attributes and qualifiers put on the generated method.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Extra {
+    Inline,
+    MustUse,
+    Const,
+    Attr(Meta),
+}
+
+/// Options for a single getter. These are shared with `MutGetters` and `With`, which read
+/// them from their own attributes.
 #[derive(Debug, Clone, PartialEq, Eq)]
-enum Action {    
+pub enum Action {
     Skip,
     Rename(Ident),
+    Mode(Mode),
+    Vis(Vis),
+    Extra(Extra),
+    Into,
+    Include,
+}
+
+fn parse_mode(input: ParseStream) -> Result<Option<Mode>> {
+    syn::custom_keyword!(copy);
+    syn::custom_keyword!(clone);
+    syn::custom_keyword!(deref);
+
+    if input.peek(syn::Token![ref]) {
+        let _ = input.parse::<syn::Token![ref]>()?;
+        Ok(Some(Mode::Ref))
+    } else if input.peek(copy) {
+        let _ = input.parse::<copy>()?;
+        Ok(Some(Mode::Copy))
+    } else if input.peek(clone) {
+        let _ = input.parse::<clone>()?;
+        Ok(Some(Mode::Clone))
+    } else if input.peek(deref) {
+        let _ = input.parse::<deref>()?;
+        Ok(Some(Mode::Deref))
+    } else {
+        Ok(None)
+    }
+}
+
+fn parse_extra(input: ParseStream) -> Result<Option<Extra>> {
+    syn::custom_keyword!(inline);
+    syn::custom_keyword!(must_use);
+    syn::custom_keyword!(attr);
+
+    if input.peek(inline) {
+        let _ = input.parse::<inline>()?;
+        Ok(Some(Extra::Inline))
+    } else if input.peek(must_use) {
+        let _ = input.parse::<must_use>()?;
+        Ok(Some(Extra::MustUse))
+    } else if input.peek(syn::Token![const]) {
+        let _ = input.parse::<syn::Token![const]>()?;
+        Ok(Some(Extra::Const))
+    } else if input.peek(attr) {
+        let _ = input.parse::<attr>()?;
+        let content;
+        let _ = syn::parenthesized!(content in input);
+        Ok(Some(Extra::Attr(content.parse::<Meta>()?)))
+    } else {
+        Ok(None)
+    }
 }
 
 impl Parse for Action {
     fn parse(input: ParseStream) -> Result<Self> {
         syn::custom_keyword!(skip);
         syn::custom_keyword!(rename);
+        syn::custom_keyword!(vis);
+        syn::custom_keyword!(into);
+        syn::custom_keyword!(include);
         
         if input.peek(skip) {
             let _ = input.parse::<skip>()?;
-            if !input.is_empty() {
+            if !end_of_option(input) {
                 Err(Error::new(Span::call_site(), Problem::TokensFollowSkip))
             } else {
                 Ok(Action::Skip)
@@ -43,63 +143,504 @@ impl Parse for Action {
             let _ = input.parse::<rename>()?;
             let _ = input.parse::<syn::Token![=]>()?;
             let name = input.parse::<LitStr>()?;
-            if !input.is_empty() {
+            if !end_of_option(input) {
                 Err(Error::new(Span::call_site(), Problem::TokensFollowNewName))
             } else {
                 Ok(Action::Rename(Ident::new(name.value().as_str(), Span::call_site())))
             }
+        } else if let Some(mode) = parse_mode(input)? {
+            Ok(Action::Mode(mode))
+        } else if input.peek(vis) {
+            Ok(Action::Vis(input.parse()?))
+        } else if let Some(extra) = parse_extra(input)? {
+            Ok(Action::Extra(extra))
+        } else if input.peek(into) {
+            let _ = input.parse::<into>()?;
+            Ok(Action::Into)
+        } else if input.peek(include) {
+            let _ = input.parse::<include>()?;
+            Ok(Action::Include)
         } else {
             Err(Error::new(Span::call_site(), Problem::InvalidAttribute))
         }
     }
 }
 
-fn get_action_from(attributes: &[Attribute]) -> Result<Option<Action>> {
-    let mut current: Option<Action> = None;
+pub fn get_actions_from(attributes: &[Attribute], name: &str) -> Result<Vec<Action>> {
+    let mut current: Vec<Action> = Vec::new();
     
     for attr in attributes {
         if attr.style != AttrStyle::Outer { continue; }
         
-        if attr.path.is_ident("getter") {
-            current = Some(attr.parse_args::<Action>()?);
+        if attr.path.is_ident(name) {
+            let actions = attr.parse_args_with(
+                Punctuated::<Action, syn::Token![,]>::parse_terminated
+            )?;
+            current.extend(actions);
         }
     }
     
     Ok(current)
 }
 
+/// Trait holding the getters as well as, or instead of, the inherent impl.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum GetterTrait {
+    /// Declare a trait of this name and implement it.
+    Declare(Ident),
+    /// Implement a trait declared elsewhere, usually by another `Getters` derive.
+    Implement(Path),
+}
+
+/// Struct wide options set with `#[getters(...)]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum StructAction {
+    Mode(Mode),
+    Vis(Vis),
+    Prefix(LitStr),
+    Suffix(LitStr),
+    RenameAll(RenameRule),
+    Doc(String),
+    Extra(Extra),
+    Trait(GetterTrait),
+    TraitOnly,
+    Partial,
+    Mut,
+    SkipUnderscore,
+    IntoInner,
+}
+
+impl Parse for StructAction {
+    fn parse(input: ParseStream) -> Result<Self> {
+        syn::custom_keyword!(vis);
+        syn::custom_keyword!(prefix);
+        syn::custom_keyword!(suffix);
+        syn::custom_keyword!(rename_all);
+        syn::custom_keyword!(doc);
+        syn::custom_keyword!(impl_trait);
+        syn::custom_keyword!(trait_only);
+        syn::custom_keyword!(partial);
+        syn::custom_keyword!(skip_underscore);
+        syn::custom_keyword!(into_inner);
+
+        if let Some(mode) = parse_mode(input)? {
+            Ok(StructAction::Mode(mode))
+        } else if input.peek(vis) {
+            Ok(StructAction::Vis(input.parse()?))
+        } else if input.peek(prefix) {
+            let _ = input.parse::<prefix>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            Ok(StructAction::Prefix(input.parse()?))
+        } else if input.peek(suffix) {
+            let _ = input.parse::<suffix>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            Ok(StructAction::Suffix(input.parse()?))
+        } else if input.peek(rename_all) {
+            let _ = input.parse::<rename_all>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let rule = input.parse::<LitStr>()?;
+            RenameRule::from_name(rule.value().as_str())
+                .map(StructAction::RenameAll)
+                .ok_or_else(|| Error::new_spanned(rule, Problem::UnknownRenameRule))
+        } else if input.peek(doc) {
+            let _ = input.parse::<doc>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            Ok(StructAction::Doc(input.parse::<LitStr>()?.value()))
+        } else if let Some(extra) = parse_extra(input)? {
+            Ok(StructAction::Extra(extra))
+        } else if input.peek(syn::Token![trait]) {
+            let _ = input.parse::<syn::Token![trait]>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let name = input.parse::<LitStr>()?;
+            Ok(StructAction::Trait(GetterTrait::Declare(name.parse()?)))
+        } else if input.peek(impl_trait) {
+            let _ = input.parse::<impl_trait>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let path = input.parse::<LitStr>()?;
+            Ok(StructAction::Trait(GetterTrait::Implement(path.parse()?)))
+        } else if input.peek(trait_only) {
+            let _ = input.parse::<trait_only>()?;
+            Ok(StructAction::TraitOnly)
+        } else if input.peek(partial) {
+            let _ = input.parse::<partial>()?;
+            Ok(StructAction::Partial)
+        } else if input.peek(syn::Token![mut]) {
+            let _ = input.parse::<syn::Token![mut]>()?;
+            Ok(StructAction::Mut)
+        } else if input.peek(skip_underscore) {
+            let _ = input.parse::<skip_underscore>()?;
+            Ok(StructAction::SkipUnderscore)
+        } else if input.peek(into_inner) {
+            let _ = input.parse::<into_inner>()?;
+            Ok(StructAction::IntoInner)
+        } else {
+            Err(Error::new(Span::call_site(), Problem::InvalidAttribute))
+        }
+    }
+}
+
+/// The kind of method generated from a field, which decides the attribute its options
+/// are read from.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    #[default]
+    Getter,
+    With,
+}
+
+/// Defaults applied to every field unless the field says otherwise.
+#[derive(Debug, Default)]
+pub struct Config {
+    target: Target,
+    mode: Mode,
+    vis: Vis,
+    prefix: Option<LitStr>,
+    suffix: Option<LitStr>,
+    rename_all: Option<RenameRule>,
+    doc: Option<String>,
+    extras: Vec<Extra>,
+    getter_trait: Option<GetterTrait>,
+    trait_only: bool,
+    partial: bool,
+    mutable: bool,
+    skip_underscore: bool,
+    into_inner: bool,
+}
+
+impl Config {
+    /// Configuration for `With`, whose methods are named like getters with a `with_`
+    /// prefix and read their options from `#[with(...)]`.
+    pub fn for_with() -> Self {
+        Config {
+            target: Target::With,
+            prefix: Some(LitStr::new("with_", Span::call_site())),
+            ..Config::default()
+        }
+    }
+
+    /// Name of the getter for a field without a `rename` of its own. Tuple struct fields
+    /// are named by their index, `_0`, `_1` and so on, apart from the one field of a
+    /// newtype which is called `inner`.
+    fn getter_name(&self, member: &Member, newtype: bool) -> Result<Ident> {
+        let prefix = self.prefix.as_ref().map(LitStr::value).unwrap_or_default();
+        let suffix = self.suffix.as_ref().map(LitStr::value).unwrap_or_default();
+        let plain = prefix.is_empty() && suffix.is_empty() && self.rename_all.is_none();
+
+        let base = match member {
+            Member::Named(ident) if plain => return Ok(ident.clone()),
+            Member::Unnamed(_) if newtype => "inner".to_owned(),
+            _ => member_name(member),
+        };
+
+        let name = format!("{}{}{}", prefix, base, suffix);
+        let name = match self.rename_all {
+            Some(rule) => rule.apply(name.as_str()),
+            None => name,
+        };
+        let name = if name.starts_with(|c: char| c.is_ascii_digit()) {
+            format!("_{}", name)
+        } else {
+            name
+        };
+
+        // Keywords need to be raw, and the few that can't be, like `self`, are an error
+        // along with any characters that don't belong in an identifier.
+        syn::parse_str::<Ident>(name.as_str())
+            .or_else(|_| syn::parse_str::<Ident>(format!("r#{}", name).as_str()))
+            .map_err(|_| {
+                let invalid = |lit: &&LitStr| {
+                    syn::parse_str::<Ident>(format!("x{}x", lit.value()).as_str()).is_err()
+                };
+                let lit = self.prefix.iter()
+                    .chain(self.suffix.iter())
+                    .find(invalid)
+                    .or(self.prefix.as_ref())
+                    .or(self.suffix.as_ref());
+                match lit {
+                    Some(lit) => Error::new_spanned(lit, Problem::InvalidGetterName(name)),
+                    None => Error::new_spanned(member, Problem::InvalidGetterName(name)),
+                }
+            })
+    }
+
+    /// Documentation for a getter. The field's own docs are used if it has any, otherwise
+    /// the `doc` template with `{field}` replaced by the field name.
+    fn docs(&self, field: &syn::Field, member: &Member) -> Vec<Attribute> {
+        let docs = doc_attributes(field.attrs.as_slice());
+        match self.doc {
+            Some(ref template) if docs.is_empty() => {
+                let doc = template.replace("{field}", member_name(member).as_str());
+                vec![syn::parse_quote!(#[doc = #doc])]
+            },
+            _ => docs,
+        }
+    }
+
+    /// Whether a field is left out unless it asks to be included. Markers like
+    /// `PhantomData` always are, and fields starting with `_` are with `skip_underscore`.
+    fn skipped(&self, field: &syn::Field) -> bool {
+        let underscore = field.ident
+            .as_ref()
+            .is_some_and(|ident| ident.unraw().to_string().starts_with('_'));
+
+        is_marker(&field.ty) || (self.skip_underscore && underscore)
+    }
+
+    fn from_attributes(attributes: &[Attribute]) -> Result<Self> {
+        let mut config = Config::default();
+
+        for attr in attributes {
+            if attr.style != AttrStyle::Outer { continue; }
+
+            if attr.path.is_ident("getters") {
+                let actions = attr.parse_args_with(
+                    Punctuated::<StructAction, syn::Token![,]>::parse_terminated
+                )?;
+                for action in actions {
+                    match action {
+                        StructAction::Mode(mode) => config.mode = mode,
+                        StructAction::Vis(vis) => config.vis = vis,
+                        StructAction::Prefix(prefix) => config.prefix = Some(prefix),
+                        StructAction::Suffix(suffix) => config.suffix = Some(suffix),
+                        StructAction::RenameAll(rule) => config.rename_all = Some(rule),
+                        StructAction::Doc(doc) => config.doc = Some(doc),
+                        StructAction::Extra(extra) => config.extras.push(extra),
+                        StructAction::Trait(t) => config.getter_trait = Some(t),
+                        StructAction::TraitOnly => config.trait_only = true,
+                        StructAction::Partial => config.partial = true,
+                        StructAction::Mut => config.mutable = true,
+                        StructAction::SkipUnderscore => config.skip_underscore = true,
+                        StructAction::IntoInner => config.into_inner = true,
+                    }
+                }
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// Borrowed forms of the owned types understood by the `deref` mode.
+enum Borrowed {
+    /// `&Target` reached through `Deref`.
+    Deref(TokenStream),
+    /// `&[T]` of an array `[T; N]`, reached by unsized coercion.
+    Slice(TokenStream),
+    /// `Option<&Target>` reached through `Option::as_deref`.
+    OptionDeref(TokenStream),
+    /// `Option<&T>` reached through `Option::as_ref`.
+    OptionRef(TokenStream),
+}
+
+fn type_arguments(segment: &PathSegment) -> Vec<&Type> {
+    match segment.arguments {
+        PathArguments::AngleBracketed(ref arguments) => arguments.args
+            .iter()
+            .filter_map(|argument| match argument {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn last_segment(ty: &Type) -> Option<&PathSegment> {
+    match ty {
+        Type::Path(TypePath { qself: None, path }) => path.segments.last(),
+        _ => None,
+    }
+}
+
+/// The `Deref::Target` of the well known owned types, going by the last path segment.
+fn deref_target(ty: &Type) -> Option<TokenStream> {
+    let segment = last_segment(ty)?;
+    let arguments = type_arguments(segment);
+
+    match (segment.ident.to_string().as_str(), arguments.as_slice()) {
+        ("String", []) => Some(quote!(str)),
+        ("PathBuf", []) => Some(quote!(::std::path::Path)),
+        ("OsString", []) => Some(quote!(::std::ffi::OsStr)),
+        ("Vec", [inner]) => Some(quote!([#inner])),
+        ("Box", [inner]) => Some(quote!(#inner)),
+        ("Cow", [inner]) => Some(quote!(#inner)),
+        _ => None,
+    }
+}
+
+fn borrowed(ty: &Type) -> Option<Borrowed> {
+    if let Some(target) = deref_target(ty) {
+        return Some(Borrowed::Deref(target));
+    }
+
+    if let Type::Array(array) = ty {
+        let elem = &array.elem;
+        return Some(Borrowed::Slice(quote!([#elem])));
+    }
+
+    let segment = last_segment(ty)?;
+    match (segment.ident.to_string().as_str(), type_arguments(segment).as_slice()) {
+        ("Option", [inner]) => match deref_target(inner) {
+            Some(target) => Some(Borrowed::OptionDeref(target)),
+            None => Some(Borrowed::OptionRef(quote!(#inner))),
+        },
+        _ => None,
+    }
+}
+
+/// Whether the getter body for `mode` can be evaluated in a `const fn`. Cloning and going
+/// through `Deref` need trait calls that aren't allowed there.
+fn const_possible(mode: Mode, ty: &Type) -> bool {
+    match mode {
+        Mode::Ref | Mode::Copy => true,
+        Mode::Clone => false,
+        Mode::Deref => matches!(
+            borrowed(ty),
+            None | Some(Borrowed::Slice(_)) | Some(Borrowed::OptionRef(_))
+        ),
+    }
+}
+
+/// An enum variant holding a field, along with the variant's `cfg` attributes for its
+/// match arm.
+struct Arm {
+    cfgs: Vec<Attribute>,
+    variant: Ident,
+}
+
+impl Arm {
+    fn from_variant(variant: &Variant) -> Self {
+        Arm {
+            cfgs: cfg_attributes(variant.attrs.as_slice()),
+            variant: variant.ident.clone(),
+        }
+    }
+}
+
+/// Where a getter finds its field.
+enum Access {
+    /// A field of a struct.
+    Struct,
+    /// A field shared by enum variants. `partial` is set when some variants lack it.
+    Variants {
+        arms: Vec<Arm>,
+        partial: bool,
+    },
+    /// A field of a union. Reading it is unsafe so the getters are `unsafe fn`. `mutable`
+    /// is set when a `_mut` getter is wanted too.
+    Union {
+        mutable: bool,
+    },
+}
+
 pub struct Field {
     ty: Type,    
-    name: Ident,
+    member: Member,
+    access: Access,
     getter: Ident,
+    mode: Mode,
+    vis: Visibility,
+    docs: Vec<Attribute>,
+    cfgs: Vec<Attribute>,
+    extras: Vec<Extra>,
+    constness: bool,
+    into: bool,
 }
 
 impl Field {
-    fn from_field(field: &syn::Field) -> Result<Option<Self>> {
-        let name: Ident =  field.ident
-            .clone()
-            .ok_or(Error::new(Span::call_site(), Problem::UnnamedField))?;
-        
-        match get_action_from(field.attrs.as_slice())? {
-            Some(Action::Skip) => return Ok(None),
-            Some(Action::Rename(ident)) => Ok(Some(Field {
-                ty: field.ty.clone(),
-                name: name,
-                getter: ident,
-            })),
-            None => Ok(Some(Field {
-                ty: field.ty.clone(),
-                name: name.clone(),
-                getter: name,
-            })),
+    fn from_field(
+        member: Member,
+        field: &syn::Field,
+        newtype: bool,
+        config: &Config,
+    ) -> Result<Option<Self>> {
+        let mut skip = config.skipped(field);
+        let mut rename = None;
+        let mut mode = config.mode;
+        let mut vis = config.vis.clone();
+        let mut extras: Vec<Extra> = Vec::new();
+        let mut into = false;
+
+        let attribute = match config.target {
+            Target::Getter => "getter",
+            Target::With => "with",
+        };
+
+        // Later options win over earlier ones, so a `skip` can be undone by a
+        // following `rename` or `include` and vice versa.
+        for action in get_actions_from(field.attrs.as_slice(), attribute)? {
+            match (action, config.target) {
+                (Action::Skip, _) => skip = true,
+                (Action::Include, _) => skip = false,
+                (Action::Rename(ident), _) => {
+                    skip = false;
+                    rename = Some(ident);
+                },
+                (Action::Vis(v), _) => vis = v,
+                (Action::Mode(m), Target::Getter) => mode = m,
+                (Action::Extra(extra), Target::Getter) => extras.push(extra),
+                (Action::Into, Target::With) => into = true,
+                (Action::Extra(Extra::Const), Target::With) | (Action::Mode(_), Target::With) => {
+                    return Err(Error::new_spanned(field, Problem::NotForWith));
+                },
+                (Action::Extra(extra), Target::With) => extras.push(extra),
+                (Action::Into, Target::Getter) => {
+                    return Err(Error::new_spanned(field, Problem::IntoNotForGetters));
+                },
+            }
         }
+
+        if skip {
+            return Ok(None);
+        }
+
+        // Only worked out now so a field that's skipped or renamed can't fail on a name it
+        // never uses.
+        let getter = match rename {
+            Some(ident) => ident,
+            None => config.getter_name(&member, newtype)?,
+        };
+
+        let docs = config.docs(field, &member);
+
+        // A struct wide `const` only applies to the getters that can be `const`, while
+        // asking for it on a field that can't be is an error.
+        let const_possible = const_possible(mode, &field.ty);
+        if extras.contains(&Extra::Const) && !const_possible {
+            return Err(Error::new_spanned(field, Problem::ConstNotPossible));
+        }
+        let constness = const_possible
+            && (extras.contains(&Extra::Const) || config.extras.contains(&Extra::Const));
+
+        let extras = config.extras
+            .iter()
+            .chain(extras.iter())
+            .filter(|extra| **extra != Extra::Const)
+            .cloned()
+            .collect();
+
+        Ok(Some(Field {
+            ty: field.ty.clone(),
+            member,
+            access: Access::Struct,
+            getter,
+            mode,
+            vis: vis.resolve(&field.vis),
+            docs,
+            cfgs: cfg_attributes(field.attrs.as_slice()),
+            extras,
+            constness,
+            into,
+        }))
     }
     
-    fn from_fields_named(fields_named: &FieldsNamed) -> Result<Vec<Self>> {
-        fields_named.named
-            .iter()
-            .try_fold(Vec::new(), |mut fields, field| {
-                if let Some(field) = Field::from_field(field)? {
+    pub fn from_fields(structure: &DataStruct, config: &Config) -> Result<Vec<Self>> {
+        let newtype = is_newtype(structure);
+
+        field_members(&structure.fields)
+            .into_iter()
+            .try_fold(Vec::new(), |mut fields, (member, field)| {
+                if let Some(field) = Field::from_field(member, field, newtype, config)? {
                     fields.push(field);
                 }
 
@@ -107,54 +648,489 @@ impl Field {
             })
     }
 
-    fn emit(&self) -> TokenStream {
-        let returns = &self.ty;
-        let field_name = &self.name;
-        let getter_name = &self.getter;
-        
-        match &self.ty {
-            Type::Reference(tr) => {
-                let lifetime = tr.lifetime.as_ref();
-                quote!(
-                    pub fn #getter_name(&#lifetime self) -> #returns {
-                        self.#field_name
+    /// Fields of the same name in every variant of an enum, or in some of them when
+    /// `partial` is set. A field must have the same type wherever it appears. Options are
+    /// read from the field in every variant it appears in and documentation is taken from
+    /// its first appearance.
+    fn from_variants(node: &DeriveInput, data: &DataEnum, config: &Config) -> Result<Vec<Self>> {
+        let mut shared: Vec<(Member, syn::Field, Vec<Arm>)> = Vec::new();
+        let mut mismatched: Vec<Member> = Vec::new();
+
+        for variant in data.variants.iter() {
+            for (member, field) in field_members(&variant.fields) {
+                match shared.iter_mut().find(|(m, _, _)| *m == member) {
+                    Some((_, first, arms)) => {
+                        // A field whose type differs between variants isn't shared.
+                        if first.ty != field.ty {
+                            mismatched.push(member);
+                            continue;
+                        }
+                        first.attrs.extend(
+                            field.attrs
+                                .iter()
+                                .filter(|attr| attr.path.is_ident("getter"))
+                                .cloned()
+                        );
+                        arms.push(Arm::from_variant(variant));
+                    },
+                    None => {
+                        // Variant fields have no visibility of their own so they inherit
+                        // that of the enum.
+                        let mut first = field.clone();
+                        first.vis = node.vis.clone();
+                        first.attrs.retain(|attr| !attr.path.is_ident("cfg"));
+                        shared.push((member, first, vec![Arm::from_variant(variant)]));
+                    },
+                }
+            }
+        }
+
+        let count = data.variants.len();
+        shared
+            .into_iter()
+            .filter(|(member, _, _)| !mismatched.contains(member))
+            .filter(|(_, _, arms)| config.partial || arms.len() == count)
+            .try_fold(Vec::new(), |mut fields, (member, field, arms)| {
+                if let Some(mut field) = Field::from_field(member, &field, false, config)? {
+                    field.access = Access::Variants {
+                        partial: arms.len() < count,
+                        arms,
+                    };
+                    fields.push(field);
+                }
+
+                Ok(fields)
+            })
+    }
+
+    fn from_union(data: &DataUnion, config: &Config) -> Result<Vec<Self>> {
+        let fields = Fields::Named(data.fields.clone());
+        field_members(&fields)
+            .into_iter()
+            .try_fold(Vec::new(), |mut fields, (member, field)| {
+                if let Some(mut field) = Field::from_field(member, field, false, config)? {
+                    field.access = Access::Union { mutable: config.mutable };
+                    fields.push(field);
+                }
+
+                Ok(fields)
+            })
+    }
+
+    /// The receiver, return type and body of the getter.
+    fn signature(&self) -> (TokenStream, TokenStream, TokenStream) {
+        let member = &self.member;
+
+        match self.access {
+            Access::Struct => self.signature_at(quote!(self.#member)),
+            Access::Union { .. } => {
+                let (receiver, returns, body) = self.signature_at(quote!(self.#member));
+                (receiver, returns, quote!(unsafe { #body }))
+            },
+            Access::Variants { ref arms, partial } => {
+                let binding = Ident::new("field", Span::mixed_site());
+                let (receiver, returns, body) = self.signature_at(quote!((*#binding)));
+                let (returns, body) = if partial {
+                    (quote!(Option<#returns>), quote!(Some(#body)))
+                } else {
+                    (returns, body)
+                };
+
+                let arms = arms.iter().map(|Arm { cfgs, variant }| quote!(
+                    #(#cfgs)*
+                    Self::#variant { #member: #binding, .. } => #body,
+                ));
+                let otherwise = if partial {
+                    quote!(_ => None,)
+                } else {
+                    TokenStream::new()
+                };
+
+                let body = quote!(
+                    match self {
+                        #(#arms)*
+                        #otherwise
                     }
-                )
+                );
+
+                (receiver, returns, body)
+            },
+        }
+    }
+
+    /// The receiver, return type and body of a getter for the field at `place`.
+    fn signature_at(&self, place: TokenStream) -> (TokenStream, TokenStream, TokenStream) {
+        let ty = &self.ty;
+
+        let borrowed = match self.mode {
+            Mode::Deref => borrowed(ty),
+            _ => None,
+        };
+
+        match (self.mode, borrowed, ty) {
+            (Mode::Copy, _, _) => (
+                quote!(&self),
+                quote!(#ty),
+                quote!(#place),
+            ),
+            (Mode::Clone, _, _) => (
+                quote!(&self),
+                quote!(#ty),
+                quote!(#place.clone()),
+            ),
+            (_, Some(Borrowed::Deref(target)), _) => (
+                quote!(&self),
+                quote!(&#target),
+                quote!(&*#place),
+            ),
+            (_, Some(Borrowed::Slice(slice)), _) => (
+                quote!(&self),
+                quote!(&#slice),
+                quote!(&#place),
+            ),
+            (_, Some(Borrowed::OptionDeref(target)), _) => (
+                quote!(&self),
+                quote!(Option<&#target>),
+                quote!(#place.as_deref()),
+            ),
+            (_, Some(Borrowed::OptionRef(inner)), _) => (
+                quote!(&self),
+                quote!(Option<&#inner>),
+                quote!(#place.as_ref()),
+            ),
+            // A shared reference is copied out with its own lifetime, leaving `self`
+            // borrowed only for the call. A mutable one can't be copied so it's reborrowed
+            // for as long as `self` is.
+            (_, None, Type::Reference(tr)) => match tr.mutability {
+                None => (
+                    quote!(&self),
+                    quote!(#ty),
+                    quote!(#place),
+                ),
+                Some(_) => {
+                    let elem = &tr.elem;
+                    (
+                        quote!(&self),
+                        quote!(&#elem),
+                        quote!(&*#place),
+                    )
+                },
             },
-            _ => {
+            (_, None, _) => (
+                quote!(&self),
+                quote!(&#ty),
+                quote!(&#place),
+            ),
+        }
+    }
+
+    fn unsafety(&self) -> TokenStream {
+        match self.access {
+            Access::Union { .. } => quote!(unsafe),
+            _ => TokenStream::new(),
+        }
+    }
+
+    /// A `# Safety` section for union getters, after any documentation of the field.
+    fn safety_docs(&self) -> TokenStream {
+        match self.access {
+            Access::Union { .. } => {
+                let safety = format!(
+                    "The caller must make sure `{}` is the field of the union that was last \
+                     written to, or that its bytes are otherwise a valid value of its type.",
+                    member_name(&self.member),
+                );
                 quote!(
-                    pub fn #getter_name(&self) -> &#returns {
-                        &self.#field_name
-                    }
+                    #[doc = ""]
+                    #[doc = "# Safety"]
+                    #[doc = ""]
+                    #[doc = #safety]
                 )
             },
+            _ => TokenStream::new(),
+        }
+    }
+
+    /// Name, receiver, return type and body of the `_mut` getter of a union field, if it
+    /// has one.
+    fn signature_mut(&self) -> Option<(Ident, TokenStream, TokenStream, TokenStream)> {
+        match self.access {
+            Access::Union { mutable: true } => {
+                let ty = &self.ty;
+                let member = &self.member;
+                let name = format!("{}_mut", self.getter.unraw());
+                Some((
+                    Ident::new(name.as_str(), Span::call_site()),
+                    quote!(&mut self),
+                    quote!(&mut #ty),
+                    quote!(unsafe { &mut self.#member }),
+                ))
+            },
+            _ => None,
+        }
+    }
+
+    fn mut_docs(&self) -> TokenStream {
+        let doc = format!("Mutable reference to the `{}` field.", member_name(&self.member));
+        let safety = self.safety_docs();
+        quote!(
+            #[doc = #doc]
+            #safety
+        )
+    }
+
+    fn inline(&self) -> TokenStream {
+        if self.extras.contains(&Extra::Inline) {
+            quote!(#[inline])
+        } else {
+            TokenStream::new()
         }
     }
+
+    /// Attributes for the declaration of the getter, excluding `#[inline]` which belongs
+    /// with the body.
+    fn attributes(&self) -> TokenStream {
+        let attributes = self.extras
+            .iter()
+            .filter_map(|extra| match extra {
+                Extra::MustUse => Some(quote!(#[must_use])),
+                Extra::Attr(meta) => Some(quote!(#[#meta])),
+                Extra::Inline | Extra::Const => None,
+            });
+
+        // Struct wide renaming or an explicit rename can give a name outside of snake case.
+        let allow_case = if self.getter.to_string().chars().any(char::is_uppercase) {
+            quote!(#[allow(non_snake_case)])
+        } else {
+            TokenStream::new()
+        };
+
+        quote!(
+            #(#attributes)*
+            #allow_case
+        )
+    }
+
+    fn emit(&self) -> TokenStream {
+        let getter_name = &self.getter;
+        let vis = &self.vis;
+        let docs = &self.docs;
+        let cfgs = &self.cfgs;
+        let inline = self.inline();
+        let attributes = self.attributes();
+        let constness = if self.constness {
+            quote!(const)
+        } else {
+            TokenStream::new()
+        };
+        let unsafety = self.unsafety();
+        let safety = self.safety_docs();
+        let (receiver, returns, body) = self.signature();
+
+        let getter_mut = self.signature_mut().map(|(name, receiver, returns, body)| {
+            let docs = self.mut_docs();
+            quote!(
+                #(#cfgs)*
+                #docs
+                #inline
+                #attributes
+                #vis #unsafety fn #name(#receiver) -> #returns {
+                    #body
+                }
+            )
+        });
+
+        quote!(
+            #(#cfgs)*
+            #(#docs)*
+            #safety
+            #inline
+            #attributes
+            #vis #constness #unsafety fn #getter_name(#receiver) -> #returns {
+                #body
+            }
+
+            #getter_mut
+        )
+    }
+
+    /// A `with_` method consuming `self` and returning it with the field replaced.
+    pub fn emit_with(&self) -> TokenStream {
+        let ty = &self.ty;
+        let member = &self.member;
+        let name = &self.getter;
+        let vis = &self.vis;
+        let cfgs = &self.cfgs;
+        let inline = self.inline();
+        let attributes = self.attributes();
+        let value = Ident::new("value", Span::mixed_site());
+        let doc = format!(
+            "Returns `self` with the `{}` field set to `value`.",
+            member_name(member),
+        );
+
+        let (argument, value_expr) = if self.into {
+            (quote!(impl ::std::convert::Into<#ty>), quote!(#value.into()))
+        } else {
+            (quote!(#ty), quote!(#value))
+        };
+
+        quote!(
+            #(#cfgs)*
+            #[doc = #doc]
+            #inline
+            #attributes
+            #vis fn #name(mut self, #value: #argument) -> Self {
+                self.#member = #value_expr;
+                self
+            }
+        )
+    }
+
+    /// `into_inner` for a newtype with the `into_inner` option, generated alongside its
+    /// `inner` getter.
+    fn emit_into_inner(&self) -> TokenStream {
+        let ty = &self.ty;
+        let member = &self.member;
+        let vis = &self.vis;
+        let cfgs = &self.cfgs;
+        let inline = self.inline();
+
+        quote!(
+            #(#cfgs)*
+            #[doc = "Consumes the newtype returning the value it wraps."]
+            #inline
+            #vis fn into_inner(self) -> #ty {
+                self.#member
+            }
+        )
+    }
+
+    /// The getter as declared in a generated trait.
+    fn emit_trait_item(&self) -> TokenStream {
+        let getter_name = &self.getter;
+        let docs = &self.docs;
+        let cfgs = &self.cfgs;
+        let attributes = self.attributes();
+        let unsafety = self.unsafety();
+        let safety = self.safety_docs();
+        let (receiver, returns, _) = self.signature();
+
+        let getter_mut = self.signature_mut().map(|(name, receiver, returns, _)| {
+            let docs = self.mut_docs();
+            quote!(
+                #(#cfgs)*
+                #docs
+                #attributes
+                #unsafety fn #name(#receiver) -> #returns;
+            )
+        });
+
+        quote!(
+            #(#cfgs)*
+            #(#docs)*
+            #safety
+            #attributes
+            #unsafety fn #getter_name(#receiver) -> #returns;
+
+            #getter_mut
+        )
+    }
+
+    /// The getter as implemented for a trait. Trait methods can't be `const` and have no
+    /// visibility of their own.
+    fn emit_trait_impl_item(&self) -> TokenStream {
+        let getter_name = &self.getter;
+        let cfgs = &self.cfgs;
+        let inline = self.inline();
+        let unsafety = self.unsafety();
+        let (receiver, returns, body) = self.signature();
+
+        let getter_mut = self.signature_mut().map(|(name, receiver, returns, body)| quote!(
+            #(#cfgs)*
+            #inline
+            #unsafety fn #name(#receiver) -> #returns {
+                #body
+            }
+        ));
+
+        quote!(
+            #(#cfgs)*
+            #inline
+            #unsafety fn #getter_name(#receiver) -> #returns {
+                #body
+            }
+
+            #getter_mut
+        )
+    }
 }
 
 pub struct NamedStruct<'a> {
     original: &'a DeriveInput,
     name: Ident,
     fields: Vec<Field>,
+    into_inner: bool,
+    getter_trait: Option<GetterTrait>,
+    trait_only: bool,
 }
 
 impl<'a> NamedStruct<'a> {
     pub fn emit(&self) -> TokenStream {
-        let (impl_generics, struct_generics, where_clause) = self.original.generics
-            .split_for_impl();        
+        let generics = &self.original.generics;
+        let (impl_generics, struct_generics, where_clause) = generics.split_for_impl();
         let struct_name = &self.name;
-        let methods: Vec<TokenStream> = self.fields
-            .iter()
-            .map(|field| field.emit())
-            .collect();
+
+        let inherent = if self.trait_only {
+            TokenStream::new()
+        } else {
+            let methods = self.fields.iter().map(|field| field.emit());
+            let into_inner = self.fields
+                .iter()
+                .filter(|_| self.into_inner)
+                .map(|field| field.emit_into_inner());
+            quote!(
+                impl #impl_generics #struct_name #struct_generics
+                    #where_clause
+                {
+                    #(#methods)*
+                    #(#into_inner)*
+                }
+            )
+        };
+
+        let (declaration, trait_path) = match self.getter_trait {
+            Some(GetterTrait::Declare(ref trait_name)) => {
+                let vis = &self.original.vis;
+                let doc = format!("Getters of [`{}`].", struct_name);
+                let items = self.fields.iter().map(|field| field.emit_trait_item());
+                let declaration = quote!(
+                    #[doc = #doc]
+                    #vis trait #trait_name #generics
+                        #where_clause
+                    {
+                        #(#items)*
+                    }
+                );
+                (declaration, quote!(#trait_name #struct_generics))
+            },
+            Some(GetterTrait::Implement(ref path)) => (TokenStream::new(), quote!(#path)),
+            None => return inherent,
+        };
+
+        let items = self.fields.iter().map(|field| field.emit_trait_impl_item());
 
         quote!(
-            impl #impl_generics #struct_name #struct_generics
+            #inherent
+
+            #declaration
+
+            impl #impl_generics #trait_path for #struct_name #struct_generics
                 #where_clause
             {
-                #(#methods)*
+                #(#items)*
             }
-        )        
+        )
     }
 }
 
@@ -162,14 +1138,32 @@ impl<'a> TryFrom<&'a DeriveInput> for NamedStruct<'a> {
     type Error = Error;
     
     fn try_from(node: &'a DeriveInput) -> Result<Self> {
-        let struct_data = named_struct(node)?;
-        let named_fields = named_fields(struct_data)?;
-        let fields = Field::from_fields_named(named_fields)?;
+        let config = Config::from_attributes(node.attrs.as_slice())?;
+        let (fields, newtype) = match node.data {
+            Data::Enum(ref data) => (Field::from_variants(node, data, &config)?, false),
+            Data::Union(ref data) => (Field::from_union(data, &config)?, false),
+            Data::Struct(ref data) => (Field::from_fields(data, &config)?, is_newtype(data)),
+        };
+
+        if config.mutable && !matches!(node.data, Data::Union(_)) {
+            return Err(Error::new(Span::call_site(), Problem::MutOnlyForUnions));
+        }
+
+        if config.trait_only && config.getter_trait.is_none() {
+            return Err(Error::new(Span::call_site(), Problem::TraitOnlyWithoutTrait));
+        }
+
+        if config.into_inner && !newtype {
+            return Err(Error::new(Span::call_site(), Problem::IntoInnerOnlyForNewtypes));
+        }
 
         Ok(NamedStruct {
             original: node,
             name: node.ident.clone(),
             fields,
+            into_inner: config.into_inner,
+            getter_trait: config.getter_trait,
+            trait_only: config.trait_only,
         })
     }
 }
@@ -196,6 +1190,102 @@ mod test {
         let r: Result<Action> = syn::parse_str("rename = \"chooga\" | bongle");
         assert!(r.is_err());
 
+        let a: Action = syn::parse_str("copy")?;
+        assert!(a == Action::Mode(Mode::Copy));
+
+        let a: Action = syn::parse_str("clone")?;
+        assert!(a == Action::Mode(Mode::Clone));
+
+        let a: Action = syn::parse_str("ref")?;
+        assert!(a == Action::Mode(Mode::Ref));
+
+        let r: Result<Action> = syn::parse_str("copy = true");
+        assert!(r.is_err());
+
+        let a: Action = syn::parse_str("vis = \"pub(crate)\"")?;
+        assert!(a == Action::Vis(Vis::Explicit(syn::parse_quote!(pub(crate)))));
+
+        let a: Action = syn::parse_str("vis = \"inherit\"")?;
+        assert!(a == Action::Vis(Vis::Inherit));
+
+        let r: Result<Action> = syn::parse_str("vis = \"public\"");
+        assert!(r.is_err());
+
+        let a: Action = syn::parse_str("const")?;
+        assert!(a == Action::Extra(Extra::Const));
+
+        let a: Action = syn::parse_str("attr(deprecated(note = \"old\"))")?;
+        let check = Action::Extra(Extra::Attr(syn::parse_quote!(deprecated(note = "old"))));
+        assert!(a == check);
+
+        let r: Result<Action> = syn::parse_str("attr = deprecated");
+        assert!(r.is_err());
+
+        let a: Action = syn::parse_str("into")?;
+        assert!(a == Action::Into);
+
+        let a: Action = syn::parse_str("include")?;
+        assert!(a == Action::Include);
+
+        Ok(())
+    }
+
+    #[test]
+    fn recognise_deref_targets() -> Result<()> {
+        let target = |ty: &str| -> Result<Option<String>> {
+            let ty: Type = syn::parse_str(ty)?;
+            Ok(deref_target(&ty).map(|t| t.to_string()))
+        };
+
+        assert!(target("String")? == Some("str".to_owned()));
+        assert!(target("std::string::String")? == Some("str".to_owned()));
+        assert!(target("Vec<u8>")? == Some("[u8]".to_owned()));
+        assert!(target("Box<dyn Fn()>")? == Some("dyn Fn ()".to_owned()));
+        assert!(target("Cow<'a, str>")? == Some("str".to_owned()));
+        assert!(target("PathBuf")?.is_some());
+        assert!(target("OsString")?.is_some());
+        assert!(target("HashMap<u8, u8>")?.is_none());
+        assert!(target("String<u8>")?.is_none());
+        assert!(target("&'a String")?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_action_list() -> Result<()> {
+        let parser = Punctuated::<Action, syn::Token![,]>::parse_terminated;
+
+        let actions = syn::parse::Parser::parse_str(parser, "rename = \"hello\", copy")?;
+        let check = vec![
+            Action::Rename(Ident::new("hello", Span::call_site())),
+            Action::Mode(Mode::Copy),
+        ];
+        assert!(actions.into_iter().collect::<Vec<_>>() == check);
+
+        let r = syn::parse::Parser::parse_str(parser, "skip copy");
+        assert!(r.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn getter_names() -> Result<()> {
+        let name = |attr: Attribute, member: Member| -> Result<String> {
+            let config = Config::from_attributes(&[attr])?;
+            Ok(config.getter_name(&member, false)?.to_string())
+        };
+
+        let member: Member = syn::parse_quote!(r#type);
+        assert!(name(syn::parse_quote!(#[getters(rename_all = "camelCase")]), member.clone())?
+            == "r#type");
+        assert!(name(syn::parse_quote!(#[getters(prefix = "get_")]), member.clone())?
+            == "get_type");
+        assert!(name(syn::parse_quote!(#[getters(prefix = "get-")]), member.clone()).is_err());
+        assert!(name(syn::parse_quote!(#[getters(suffix = "!")]), member).is_err());
+
+        let member: Member = syn::parse_quote!(f);
+        assert!(name(syn::parse_quote!(#[getters(prefix = "sel")]), member).is_err());
+
         Ok(())
     }
 }
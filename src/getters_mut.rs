@@ -0,0 +1,241 @@
+//! Mutable getters internals
+use std::convert::TryFrom;
+
+use proc_macro2::{TokenStream, Span};
+use quote::quote;
+use syn::{
+    DeriveInput,
+    DataStruct,
+    Member,
+    Type,
+    AttrStyle,
+    Ident,
+    Result,
+    Error,
+    Attribute,
+    Visibility,
+    punctuated::Punctuated,
+    parse::{Parse, ParseStream},
+};
+
+use crate::{
+    extract::{
+        named_struct,
+        field_members,
+        is_newtype,
+        is_marker,
+        member_name,
+        cfg_attributes,
+        Vis,
+    },
+    faultmsg::Problem,
+    getters::{get_actions_from, Action, Extra},
+};
+
+/// Struct wide options set with `#[getters_mut(...)]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum StructAction {
+    Vis(Vis),
+    OptIn,
+}
+
+impl Parse for StructAction {
+    fn parse(input: ParseStream) -> Result<Self> {
+        syn::custom_keyword!(vis);
+        syn::custom_keyword!(opt_in);
+
+        if input.peek(vis) {
+            Ok(StructAction::Vis(input.parse()?))
+        } else if input.peek(opt_in) {
+            let _ = input.parse::<opt_in>()?;
+            Ok(StructAction::OptIn)
+        } else {
+            Err(Error::new(Span::call_site(), Problem::InvalidAttribute))
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Config {
+    vis: Vis,
+    opt_in: bool,
+}
+
+impl Config {
+    fn from_attributes(attributes: &[Attribute]) -> Result<Self> {
+        let mut config = Config::default();
+
+        for attr in attributes {
+            if attr.style != AttrStyle::Outer { continue; }
+
+            if attr.path.is_ident("getters_mut") {
+                let actions = attr.parse_args_with(
+                    Punctuated::<StructAction, syn::Token![,]>::parse_terminated
+                )?;
+                for action in actions {
+                    match action {
+                        StructAction::Vis(vis) => config.vis = vis,
+                        StructAction::OptIn => config.opt_in = true,
+                    }
+                }
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// Name of the mutable getter for a field without a `rename` of its own, which is that of
+/// its immutable getter followed by `_mut`.
+fn getter_name(member: &Member, newtype: bool) -> Ident {
+    let base = match member {
+        Member::Unnamed(_) if newtype => "inner".to_owned(),
+        Member::Unnamed(_) => format!("_{}", member_name(member)),
+        Member::Named(_) => member_name(member),
+    };
+
+    Ident::new(format!("{}_mut", base).as_str(), Span::call_site())
+}
+
+pub struct Field {
+    ty: Type,
+    member: Member,
+    getter: Ident,
+    vis: Visibility,
+    cfgs: Vec<Attribute>,
+    attributes: Vec<TokenStream>,
+}
+
+impl Field {
+    fn from_field(
+        member: Member,
+        field: &syn::Field,
+        newtype: bool,
+        config: &Config,
+    ) -> Result<Option<Self>> {
+        // Any `#[getter_mut]` attribute selects the field when the struct is `opt_in`, a
+        // bare one having no options to parse. Markers like `PhantomData` need `include`.
+        let marked = field.attrs.iter().any(|attr| attr.path.is_ident("getter_mut"));
+        let attrs: Vec<Attribute> = field.attrs
+            .iter()
+            .filter(|attr| !attr.tokens.is_empty())
+            .cloned()
+            .collect();
+
+        let mut skip = (config.opt_in && !marked) || is_marker(&field.ty);
+        let mut getter = getter_name(&member, newtype);
+        let mut vis = config.vis.clone();
+        let mut attributes: Vec<TokenStream> = Vec::new();
+
+        for action in get_actions_from(attrs.as_slice(), "getter_mut")? {
+            match action {
+                Action::Skip => skip = true,
+                Action::Include => skip = false,
+                Action::Rename(ident) => {
+                    skip = false;
+                    getter = ident;
+                },
+                Action::Vis(v) => vis = v,
+                Action::Extra(Extra::Inline) => attributes.push(quote!(#[inline])),
+                Action::Extra(Extra::MustUse) => attributes.push(quote!(#[must_use])),
+                Action::Extra(Extra::Attr(meta)) => attributes.push(quote!(#[#meta])),
+                Action::Mode(_) | Action::Extra(Extra::Const) | Action::Into => return Err(
+                    Error::new_spanned(field, Problem::NotForMutGetters)
+                ),
+            }
+        }
+
+        if skip {
+            return Ok(None);
+        }
+
+        Ok(Some(Field {
+            ty: field.ty.clone(),
+            member,
+            getter,
+            vis: vis.resolve(&field.vis),
+            cfgs: cfg_attributes(field.attrs.as_slice()),
+            attributes,
+        }))
+    }
+
+    fn from_fields(structure: &DataStruct, config: &Config) -> Result<Vec<Self>> {
+        let newtype = is_newtype(structure);
+
+        field_members(&structure.fields)
+            .into_iter()
+            .try_fold(Vec::new(), |mut fields, (member, field)| {
+                if let Some(field) = Field::from_field(member, field, newtype, config)? {
+                    fields.push(field);
+                }
+
+                Ok(fields)
+            })
+    }
+
+    fn emit(&self) -> TokenStream {
+        let ty = &self.ty;
+        let member = &self.member;
+        let getter_name = &self.getter;
+        let vis = &self.vis;
+        let cfgs = &self.cfgs;
+        let attributes = &self.attributes;
+        let doc = format!("Mutable reference to the `{}` field.", member_name(member));
+
+        // An explicit rename can give a name outside of snake case.
+        let allow_case = if getter_name.to_string().chars().any(char::is_uppercase) {
+            quote!(#[allow(non_snake_case)])
+        } else {
+            TokenStream::new()
+        };
+
+        quote!(
+            #(#cfgs)*
+            #[doc = #doc]
+            #(#attributes)*
+            #allow_case
+            #vis fn #getter_name(&mut self) -> &mut #ty {
+                &mut self.#member
+            }
+        )
+    }
+}
+
+pub struct NamedStruct<'a> {
+    original: &'a DeriveInput,
+    name: Ident,
+    fields: Vec<Field>,
+}
+
+impl<'a> NamedStruct<'a> {
+    pub fn emit(&self) -> TokenStream {
+        let (impl_generics, struct_generics, where_clause) = self.original.generics
+            .split_for_impl();
+        let struct_name = &self.name;
+        let methods = self.fields.iter().map(|field| field.emit());
+
+        quote!(
+            impl #impl_generics #struct_name #struct_generics
+                #where_clause
+            {
+                #(#methods)*
+            }
+        )
+    }
+}
+
+impl<'a> TryFrom<&'a DeriveInput> for NamedStruct<'a> {
+    type Error = Error;
+
+    fn try_from(node: &'a DeriveInput) -> Result<Self> {
+        let config = Config::from_attributes(node.attrs.as_slice())?;
+        let struct_data = named_struct(node)?;
+        let fields = Field::from_fields(struct_data, &config)?;
+
+        Ok(NamedStruct {
+            original: node,
+            name: node.ident.clone(),
+            fields,
+        })
+    }
+}
@@ -0,0 +1,240 @@
+//! Homogeneous internals
+use std::convert::TryFrom;
+
+use proc_macro2::{TokenStream, Span};
+use quote::quote;
+use syn::{
+    DeriveInput,
+    Member,
+    Type,
+    Ident,
+    Result,
+    Error,
+    AttrStyle,
+    Attribute,
+    Visibility,
+    punctuated::Punctuated,
+    parse::{Parse, ParseStream},
+};
+
+use crate::{
+    extract::{named_struct, member_name, cfg_attributes, cfg_gate, Vis},
+    faultmsg::Problem,
+    dissolve::selected_fields,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Action {
+    Vis(Vis),
+}
+
+impl Parse for Action {
+    fn parse(input: ParseStream) -> Result<Self> {
+        syn::custom_keyword!(vis);
+
+        if input.peek(vis) {
+            Ok(Action::Vis(input.parse()?))
+        } else {
+            Err(Error::new(Span::call_site(), Problem::InvalidAttribute))
+        }
+    }
+}
+
+fn homogeneous_actions_from(attributes: &[Attribute]) -> Result<Vec<Action>> {
+    let mut current: Vec<Action> = Vec::new();
+
+    for attr in attributes {
+        if attr.style != AttrStyle::Outer { continue; }
+
+        if attr.path.is_ident("homogeneous") {
+            let actions = attr.parse_args_with(
+                Punctuated::<Action, syn::Token![,]>::parse_terminated
+            )?;
+            current.extend(actions);
+        }
+    }
+
+    Ok(current)
+}
+
+pub struct NamedStruct<'a> {
+    original: &'a DeriveInput,
+    name: Ident,
+    members: Vec<Member>,
+    ty: Type,
+    vis: Visibility,
+    cfg: TokenStream,
+}
+
+impl<'a> NamedStruct<'a> {
+    pub fn emit(&self) -> TokenStream {
+        let (impl_generics, struct_generics, where_clause) = self.original.generics
+            .split_for_impl();
+        let struct_name = &self.name;
+        let vis = &self.vis;
+        let cfg = &self.cfg;
+        let ty = &self.ty;
+        let members = &self.members;
+        let count = members.len();
+        let indices: Vec<usize> = (0..count).collect();
+        let index = Ident::new("index", Span::mixed_site());
+
+        let order = members
+            .iter()
+            .map(member_name)
+            .collect::<Vec<String>>()
+            .join(", ");
+        let into_doc = format!(
+            "Consumes `{}` returning an array of its fields in the order `[{}]`.",
+            struct_name,
+            order,
+        );
+        let refs_doc = format!(
+            "Returns an array of references to the fields of `{}` in the order `[{}]`.",
+            struct_name,
+            order,
+        );
+        let iter_doc = format!(
+            "Iterates over references to the fields of `{}` in the order `[{}]`.",
+            struct_name,
+            order,
+        );
+        let iter_mut_doc = format!(
+            "Iterates over mutable references to the fields of `{}` in the order `[{}]`.",
+            struct_name,
+            order,
+        );
+        let panic = format!(
+            "index out of bounds: `{}` has {} fields but the index is {{}}",
+            struct_name,
+            count,
+        );
+
+        quote!(
+            #cfg
+            impl #impl_generics #struct_name #struct_generics
+                #where_clause
+            {
+                #[doc = #into_doc]
+                #vis fn into_array(self) -> [#ty; #count] {
+                    [#(self.#members),*]
+                }
+
+                #[doc = #refs_doc]
+                #vis fn as_array_refs(&self) -> [&#ty; #count] {
+                    [#(&self.#members),*]
+                }
+
+                #[doc = #iter_doc]
+                #vis fn iter_fields(&self) -> impl ::std::iter::Iterator<Item = &#ty> {
+                    ::std::iter::IntoIterator::into_iter([#(&self.#members),*])
+                }
+
+                #[doc = #iter_mut_doc]
+                #vis fn iter_fields_mut(&mut self) -> impl ::std::iter::Iterator<Item = &mut #ty> {
+                    ::std::iter::IntoIterator::into_iter([#(&mut self.#members),*])
+                }
+            }
+
+            #cfg
+            impl #impl_generics ::std::ops::Index<usize> for #struct_name #struct_generics
+                #where_clause
+            {
+                type Output = #ty;
+
+                fn index(&self, #index: usize) -> &#ty {
+                    match #index {
+                        #(#indices => &self.#members,)*
+                        _ => ::std::panic!(#panic, #index),
+                    }
+                }
+            }
+
+            #cfg
+            impl #impl_generics ::std::ops::IndexMut<usize> for #struct_name #struct_generics
+                #where_clause
+            {
+                fn index_mut(&mut self, #index: usize) -> &mut #ty {
+                    match #index {
+                        #(#indices => &mut self.#members,)*
+                        _ => ::std::panic!(#panic, #index),
+                    }
+                }
+            }
+        )
+    }
+}
+
+impl<'a> TryFrom<&'a DeriveInput> for NamedStruct<'a> {
+    type Error = Error;
+
+    fn try_from(node: &'a DeriveInput) -> Result<Self> {
+        let struct_data = named_struct(node)?;
+        let fields = selected_fields(struct_data, "homogeneous")?;
+
+        // Every field goes in the array so it takes its type from the first one.
+        let ty = match fields.first() {
+            Some((_, field)) => field.ty.clone(),
+            None => return Err(Error::new_spanned(node, Problem::HomogeneousWithoutFields)),
+        };
+        if let Some((_, field)) = fields.iter().find(|(_, field)| field.ty != ty) {
+            return Err(Error::new_spanned(&field.ty, Problem::FieldTypeMismatch));
+        }
+
+        // As with `dissolve`, the array needs every field to exist.
+        let cfgs: Vec<Attribute> = fields
+            .iter()
+            .flat_map(|(_, field)| cfg_attributes(field.attrs.as_slice()))
+            .collect();
+        let cfg = cfg_gate(cfgs.as_slice())?;
+
+        let mut vis = Vis::default();
+        for action in homogeneous_actions_from(node.attrs.as_slice())? {
+            match action {
+                Action::Vis(v) => vis = v,
+            }
+        }
+
+        Ok(NamedStruct {
+            original: node,
+            name: node.ident.clone(),
+            members: fields.into_iter().map(|(member, _)| member).collect(),
+            ty,
+            vis: vis.resolve(&node.vis),
+            cfg,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn field_types() {
+        let node: DeriveInput = syn::parse_quote!(
+            struct Mixed {
+                a: u8,
+                #[homogeneous(skip)]
+                b: u16,
+                c: u8,
+            }
+        );
+        assert!(NamedStruct::try_from(&node).is_ok());
+
+        let node: DeriveInput = syn::parse_quote!(
+            struct Mixed {
+                a: u8,
+                b: u16,
+            }
+        );
+        assert!(NamedStruct::try_from(&node).is_err());
+
+        let node: DeriveInput = syn::parse_quote!(
+            struct Empty {
+                marker: std::marker::PhantomData<u8>,
+            }
+        );
+        assert!(NamedStruct::try_from(&node).is_err());
+    }
+}
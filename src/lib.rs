@@ -1,18 +1,52 @@
-//! This library provides two derive macros. One, `Getters` for autogenerating getters and
-//! `Dissolve` for consuming a struct returning a tuple of all fields. They can only be
-//! used on named structs.
+//! This library provides derive macros for the boilerplate around struct fields. The main
+//! two are `Getters` for autogenerating getters and `Dissolve` for consuming a struct
+//! returning a tuple of all fields, with `DissolveRef` and `DissolveMut` borrowing them
+//! instead. They can be used on named structs, tuple structs and unit structs. `Variants`
+//! does much the same for the variants of an enum. `MutGetters` generates mutable getters
+//! for structs that need some fields changed in place, `Setters` generates chainable
+//! setters and `With` generates methods replacing a field of an owned value. `New` goes
+//! the other way to `Dissolve`, building a struct from its fields. `Homogeneous` treats a
+//! struct whose fields share a type as an array of them.
 //!
 //! # Derives
 //!
-//! Only named structs can derive `Getters` or `Dissolve`.
+//! Only structs can derive `Dissolve`. `Getters` can also be derived on enums, see
+//! [Enums](#enums), and unions, see [Unions](#unions). Fields of tuple structs get getters
+//! named after their position, `_0()`, `_1()` and so on, which can be renamed as usual.
+//! A newtype, being a tuple struct of one field, instead gets an `inner()` getter. With
+//! `#[getters(into_inner)]` it also gets an `into_inner(self)` method consuming it, which
+//! can't be used on a newtype that implements `Drop`. Unit structs dissolve into `()`.
+//!
+//!```edition2018
+//! # use derive_getters::{Getters, Dissolve};
+//! #[derive(Getters)]
+//! #[getters(into_inner)]
+//! struct UserId(u64);
+//!
+//! #[derive(Getters, Dissolve)]
+//! struct Pair(#[getter(rename = "left")] String, u8);
+//!
+//! let id = UserId(7);
+//! assert!(*id.inner() == 7);
+//! assert!(id.into_inner() == 7);
+//!
+//! let pair = Pair("Left".to_owned(), 2);
+//! assert!(pair.left() == "Left");
+//! assert!(*pair._1() == 2);
+//! let (left, right) = pair.dissolve();
+//! ```
 //!
 //! # `Getter` methods generated
 //!
 //! The getter methods generated shall bear the same name as the struct fields and be
-//! publicly visible. The methods return an immutable reference to the struct field of the
-//! same name. If there is already a method defined with that name there'll be a collision.
-//! In these cases one of two attributes can be set to either `skip` or `rename` the getter.
-//! 
+//! publicly visible unless told otherwise. The methods return an immutable reference to
+//! the struct field of the same name. If there is already a method defined with that name
+//! there'll be a collision. In these cases one of two attributes can be set to either
+//! `skip` or `rename` the getter.
+//!
+//! Fields that are shared references, `&'a T`, are returned as they are so the reference
+//! keeps its lifetime `'a` while `self` is only borrowed for the call. Fields that are
+//! mutable references, `&'a mut T`, are reborrowed as `&T` for as long as `self` is.
 //!
 //! # `Getters` Usage
 //!
@@ -26,10 +60,8 @@
 //!     num: u64,    
 //! }
 //! 
-//! fn main() {
-//!     let number = Number { num: 655 };
-//!     assert!(number.num() == &655);
-//! }
+//! let number = Number { num: 655 };
+//! assert!(number.num() == &655);
 //! ```
 //!
 //! Here, a method called `num()` has been created for the `Number` struct which gives a
@@ -74,14 +106,69 @@
 //! # fn main() { }
 //! ```
 //!
+//! As are const generics, with or without defaults. All the derives handle them.
+//! ```edition2018
+//! # use derive_getters::Getters;
+//! #[derive(Getters)]
+//! struct Buffer<T, const N: usize = 16> {
+//!     #[getter(deref)]
+//!     data: [T; N],
+//!     len: usize,
+//! }
+//!
+//! let buffer: Buffer<u8> = Buffer { data: [0; 16], len: 0 };
+//! let data: &[u8] = buffer.data();
+//! assert!(data.len() == 16);
+//! ```
+//!
 //! # `Getter` Attributes
-//! Getters can be further configured to either skip or rename a getter.
+//! Getters can be further configured to either skip or rename a getter, or to change
+//! how the field is handed back. Several options can be given in one attribute by
+//! separating them with commas, eg; `#[getter(rename = "number", copy)]`.
+//!
+//! * `#[getter(skip)]`
+//!   Will skip generating a getter for the field being decorated.
+//!
+//! * `#[getter(include)]`
+//!   Generates a getter for a field that would otherwise be left out. Fields of type
+//!   `PhantomData` or `PhantomPinned` hold no data so don't get getters by default.
+//!
+//! * `#[getter(rename = "name")]`
+//!   Changes the name of the getter (default is the field name) to "name".
+//!
+//! * `#[getter(copy)]`
+//!   The getter returns a copy of the field, `T`, instead of `&T`. The field type must
+//!   implement `Copy`.
+//!
+//! * `#[getter(clone)]`
+//!   The getter returns `self.field.clone()`. Handy for `Rc`, `Arc` and the like.
+//!
+//! * `#[getter(deref)]`
+//!   The getter returns the borrowed form of some common owned types. `String` gives
+//!   `&str`, `Vec<T>` gives `&[T]`, `Box<T>` gives `&T`, `PathBuf` gives `&Path`,
+//!   `OsString` gives `&OsStr`, `Cow<'_, B>` gives `&B` and an array `[T; N]` gives `&[T]`.
+//!   An `Option` of one of these gives `Option<&Target>` and any other `Option<T>` gives
+//!   `Option<&T>`. Types are recognised by name only. Unrecognised types return `&T` as
+//!   usual.
 //!
-//! * #[getter(skip)]
-//! Will skip generating a getter for the field being decorated.
+//! * `#[getter(ref)]`
+//!   The getter returns `&T`. This is the default and is only needed to override a struct
+//!   wide `copy`, `clone` or `deref`.
 //!
-//! * #[getter(rename = "name")]
-//! Changes the name of the getter (default is the field name) to "name".
+//! * `#[getter(vis = "pub(crate)")]`
+//!   Sets the visibility of the getter. Any visibility Rust accepts can be given, with an
+//!   empty string making the getter private. The special value `"inherit"` gives the
+//!   getter the same visibility as its field. The default is `pub`.
+//!
+//! * `#[getter(inline)]` and `#[getter(must_use)]`
+//!   Mark the getter `#[inline]` or `#[must_use]`.
+//!
+//! * `#[getter(attr(...))]`
+//!   Puts any other attribute on the getter, eg; `#[getter(attr(deprecated))]`.
+//!
+//! * `#[getter(const)]`
+//!   Makes the getter a `const fn`. Getters that `clone` or `deref` their field can't be
+//!   `const`, except for the `Option<&T>` returned for an unrecognised `Option<T>`.
 //!
 //!```edition2018
 //! # use derive_getters::Getters;
@@ -99,11 +186,290 @@
 //! # fn main() { }
 //! ```
 //!
+//! # `Getters` Struct Attributes
+//! The `copy`, `clone`, `deref`, `ref` and `vis` options can also be set for every field at
+//! once with the `#[getters(...)]` struct attribute. Field attributes override the struct
+//! wide setting. The `inline`, `must_use` and `attr(...)` options put their attributes on
+//! every getter, in addition to any from the field. A struct wide `const` makes every
+//! getter `const` that can be.
+//!
+//! The struct attribute also sets naming conventions for the getters.
+//!
+//! * `#[getters(prefix = "get_")]` and `#[getters(suffix = "_ref")]`
+//!   Put text in front of or after each field name.
+//!
+//! * `#[getters(rename_all = "camelCase")]`
+//!   Converts the case of each name, after any prefix and suffix have been added. Accepts
+//!   `"lowercase"`, `"UPPERCASE"`, `"PascalCase"`, `"camelCase"`, `"snake_case"` and
+//!   `"SCREAMING_SNAKE_CASE"`. Getters named outside of snake case are marked
+//!   `#[allow(non_snake_case)]`.
+//!
+//! A field with its own `#[getter(rename = "name")]` is named exactly "name", ignoring all
+//! struct wide naming.
+//!
+//! Any `///` documentation on a field is copied onto its getter. Fields without
+//! documentation can be given some with a template.
+//!
+//! * `#[getters(doc = "Returns the `{field}` field.")]`
+//!   Documents each undocumented getter with the template, replacing `{field}` with the
+//!   field name.
+//!
+//! Fields named with a leading underscore are often private details, and can be left out
+//! along with the marker fields.
+//!
+//! * `#[getters(skip_underscore)]`
+//!   Skips fields whose names start with `_`. A field can still be given a getter with
+//!   `#[getter(include)]`.
+//!
+//!```edition2018
+//! # use derive_getters::Getters;
+//! #[derive(Getters)]
+//! #[getters(prefix = "get_")]
+//! struct Person {
+//!     name: String,
+//!
+//!     #[getter(rename = "years")]
+//!     age: u8,
+//! }
+//!
+//! let person = Person { name: "Alice".to_owned(), age: 30 };
+//! assert!(person.get_name() == "Alice");
+//! assert!(*person.years() == 30);
+//! ```
+//!
+//!```edition2018
+//! # use derive_getters::Getters;
+//! use std::rc::Rc;
+//!
+//! #[derive(Getters)]
+//! #[getters(copy)]
+//! struct Point {
+//!     x: i64,
+//!     y: i64,
+//!
+//!     #[getter(clone)]
+//!     label: Rc<str>,
+//!
+//!     #[getter(deref)]
+//!     tags: Vec<String>,
+//! }
+//!
+//! let point = Point { x: 1, y: -2, label: Rc::from("origin"), tags: Vec::new() };
+//! assert!(point.x() + point.y() == -1);
+//! assert!(&*point.label() == "origin");
+//! let tags: &[String] = point.tags();
+//! assert!(tags.is_empty());
+//! ```
+//!
+//! # Getter Traits
+//! The getters can also be put in a trait so they can be used from generic code.
+//!
+//! * `#[getters(trait = "Name")]`
+//!   Declares a trait called `Name`, with the same visibility and generic parameters as
+//!   the struct, holding every getter and implements it for the struct. The inherent
+//!   getters are still generated.
+//!
+//! * `#[getters(impl_trait = "path::to::Name")]`
+//!   Implements a trait declared elsewhere, such as by another struct's `trait` option.
+//!   This lets several structs share one read only interface.
+//!
+//! * `#[getters(trait_only)]`
+//!   Leaves out the inherent getters so they're only reachable through the trait.
+//!
+//!```edition2018
+//! # use derive_getters::Getters;
+//! #[derive(Getters)]
+//! #[getters(trait = "PersonFields")]
+//! pub struct Person {
+//!     name: String,
+//! }
+//!
+//! #[derive(Getters)]
+//! #[getters(impl_trait = "PersonFields", trait_only)]
+//! pub struct Robot {
+//!     name: String,
+//! }
+//!
+//! fn greet(person: &impl PersonFields) -> String {
+//!     format!("Hello {}", person.name())
+//! }
+//!
+//! assert!(greet(&Person { name: "Alice".to_owned() }) == "Hello Alice");
+//! assert!(greet(&Robot { name: "Robbie".to_owned() }) == "Hello Robbie");
+//! ```
+//!
+//! # Enums
+//! `Getters` can be derived on an enum to get the fields that every variant shares. A
+//! field is shared when it has the same name, or position for tuple variants, and the same
+//! type in every variant. Fields whose type differs between variants get no getter. The
+//! getter matches on `self` to find the field.
+//!
+//! Options for a shared field are read from the field in every variant, so it only needs
+//! a `#[getter(...)]` attribute in one of them. Its documentation comes from the first
+//! variant it appears in.
+//!
+//! * `#[getters(partial)]`
+//!   Also generates getters for fields present in only some variants. These return an
+//!   `Option` which is `None` for variants without the field.
+//!
+//!```edition2018
+//! # use derive_getters::Getters;
+//! #[derive(Getters)]
+//! #[getters(partial)]
+//! enum Event {
+//!     Created { id: u64, name: String },
+//!     Deleted { id: u64 },
+//! }
+//!
+//! let event = Event::Deleted { id: 4 };
+//! assert!(*event.id() == 4);
+//! assert!(event.name().is_none());
+//! ```
+//!
+//! # Unions
+//! `Getters` derived on a union generates `unsafe fn` getters, as reading a union field is
+//! unsafe. Each is documented with a `# Safety` section so callers know what they're
+//! promising. The getters take the same options as those of a struct.
+//!
+//! * `#[getters(mut)]`
+//!   Also generates a `field_mut(&mut self) -> &mut T` getter for every field. This option
+//!   is only accepted on unions.
+//!
+//!```edition2018
+//! # use derive_getters::Getters;
+//! #[derive(Getters)]
+//! #[getters(mut)]
+//! union Number {
+//!     #[getter(copy)]
+//!     int: u32,
+//!     #[getter(copy)]
+//!     float: f32,
+//! }
+//!
+//! let mut number = Number { int: 1 };
+//! unsafe {
+//!     *number.int_mut() += 1;
+//!     assert!(number.int() == 2);
+//! }
+//! ```
+//!
+//! # Conditional Fields
+//! A field behind a `#[cfg(...)]` attribute has the same attribute placed on its getter.
+//! The `dissolve`, `dissolve_ref` and `dissolve_mut` methods are placed behind every `cfg`
+//! of every field, as the tuple they return can only be written when all fields exist.
+//! `#[cfg_attr(...)]` is expanded by the compiler before the derive sees the struct, so it
+//! can be used to conditionally apply `getter` or `dissolve` attributes.
+//!
+//! # `MutGetters`
+//! Deriving `MutGetters` on a struct generates a `field_mut(&mut self) -> &mut T` method
+//! for each field, named after its getter with `_mut` added. Fields are configured with
+//! `#[getter_mut(...)]`, which takes the `skip`, `include`, `rename`, `vis`, `inline`,
+//! `must_use` and `attr(...)` options of `#[getter(...)]`. A `rename` gives the full name
+//! of the method. As with getters, `PhantomData` and `PhantomPinned` fields are left out
+//! unless included.
+//!
+//! * `#[getters_mut(opt_in)]`
+//!   Only generates mutable getters for fields marked with `#[getter_mut]`, leaving the
+//!   rest read only.
+//!
+//! * `#[getters_mut(vis = "pub(crate)")]`
+//!   Sets the visibility of every mutable getter.
+//!
+//!```edition2018
+//! # use derive_getters::{Getters, MutGetters};
+//! #[derive(Getters, MutGetters)]
+//! #[getters_mut(opt_in)]
+//! struct Account {
+//!     id: u64,
+//!
+//!     #[getter_mut]
+//!     balance: i64,
+//! }
+//!
+//! let mut account = Account { id: 1, balance: 0 };
+//! *account.balance_mut() += 10;
+//! assert!(*account.balance() == 10);
+//! ```
+//!
+//! # `Setters`
+//! Deriving `Setters` on a struct generates a `set_field(&mut self, value: T) -> &mut Self`
+//! method for each field, so calls can be chained. Tuple struct fields are set with
+//! `set_0()`, `set_1()` and so on, with a newtype's being `set_inner()`. Fields are
+//! configured with `#[setter(...)]`.
+//!
+//! * `#[setter(skip)]` and `#[setter(rename = "name")]`
+//!   Skip the setter or give it the full name "name".
+//!
+//! * `#[setter(into)]`
+//!   The setter takes `impl Into<T>` and converts it.
+//!
+//! * `#[setter(vis = "pub(crate)")]`
+//!   Sets the visibility of the setter, with `"inherit"` using that of the field.
+//!
+//! * `#[setter(validate = "path::to_fn", error = "Type")]`
+//!   Calls `path::to_fn(&value)` before setting the field. The validator returns a
+//!   `Result<(), E>` and the setter returns `Result<&mut Self, Type>`, converting the error
+//!   with `?`. The field is left alone if validation fails.
+//!
+//! The `into`, `vis` and `error` options can be set for every field with the
+//! `#[setters(...)]` struct attribute, so one error type covers all validated fields.
+//!
+//!```edition2018
+//! # use derive_getters::{Getters, Setters};
+//! fn nonzero(port: &u16) -> Result<(), String> {
+//!     if *port == 0 { Err("port can't be zero".to_owned()) } else { Ok(()) }
+//! }
+//!
+//! #[derive(Getters, Setters)]
+//! #[setters(into)]
+//! struct Server {
+//!     host: String,
+//!
+//!     #[setter(validate = "nonzero", error = "String")]
+//!     port: u16,
+//! }
+//!
+//! let mut server = Server { host: String::new(), port: 80 };
+//! server.set_host("localhost").set_port(8080u16).unwrap();
+//! assert!(server.host() == "localhost");
+//! assert!(server.set_port(0u16).is_err());
+//! assert!(*server.port() == 8080);
+//! ```
+//!
+//! # `With`
+//! Deriving `With` on a struct generates a `with_field(self, value: T) -> Self` method for
+//! each field, returning the struct with that field replaced. Tuple struct fields give
+//! `with_0()`, `with_1()` and so on, with a newtype's being `with_inner()`. Fields are
+//! configured with `#[with(...)]`, which takes the `skip`, `rename`, `vis`, `inline`,
+//! `must_use` and `attr(...)` options of `#[getter(...)]` along with one more.
+//!
+//! * `#[with(into)]`
+//!   The method takes `impl Into<T>` and converts it.
+//!
+//!```edition2018
+//! # use derive_getters::{Getters, With};
+//! #[derive(Getters, With)]
+//! struct Config {
+//!     #[with(into)]
+//!     host: String,
+//!     port: u16,
+//! }
+//!
+//! let config = Config { host: String::new(), port: 0 }
+//!     .with_host("localhost")
+//!     .with_port(8080);
+//! assert!(config.host() == "localhost");
+//! assert!(*config.port() == 8080);
+//! ```
+//!
 //! # `Dissolve` method generated
 //!
-//! Deriving `Dissolve` on a named struct will generate a method `dissolve(self)` which
-//! shall return a tuple of all struct fields in the order they were defined. Calling this
-//! method consumes the struct. The name of this method can be changed with an attribute.
+//! Deriving `Dissolve` on a struct will generate a method `dissolve(self)` which
+//! shall return a tuple of the struct fields in the order they were defined. Calling this
+//! method consumes the struct. A struct of one field gives a one element tuple, `(T,)`.
+//! The name of this method can be changed with an attribute. The generated method is
+//! documented with the order of the fields in the tuple, as are the methods from
+//! `DissolveRef` and `DissolveMut`.
 //!
 //! # `Dissolve` usage
 //!
@@ -116,29 +482,48 @@
 //!     count: usize,
 //! }
 //! 
-//! fn main() {
-//!     let stuff = Stuff {
-//!         name: "Hogie".to_owned(),
-//!         price: 123.4f64,
-//!         count: 100,
-//!     };
+//! let stuff = Stuff {
+//!     name: "Hogie".to_owned(),
+//!     price: 123.4f64,
+//!     count: 100,
+//! };
 //!
-//!     let (n, p, c) = stuff.dissolve();
-//!     assert!(n == "Hogie");
-//!     assert!(p == 123.4f64);
-//!     assert!(c == 100);
-//! }
+//! let (n, p, c) = stuff.dissolve();
+//! assert!(n == "Hogie");
+//! assert!(p == 123.4f64);
+//! assert!(c == 100);
 //! ```
 //!
 //! # `Dissolve` Attributes
-//! You can rename the `dissolve` function by using a struct attribute.
+//! You can rename the `dissolve` function or change its visibility by using a struct
+//! attribute. `DissolveRef` and `DissolveMut` take the same options through
+//! `#[dissolve_ref(...)]` and `#[dissolve_mut(...)]`.
+//!
+//! * `#[dissolve(rename = "name")]`
+//!
+//! * `#[dissolve(vis = "pub(crate)")]`
+//!   As with getters, `"inherit"` gives the method the same visibility as the struct.
+//!
+//! * `#[dissolve(impl_from)]`
+//!   Also implements `From<Struct>` for the tuple so the struct can be used with `Into`.
+//!   With `#[dissolve_ref(impl_from)]` and `#[dissolve_mut(impl_from)]` the tuples of
+//!   references implement `From<&Struct>` and `From<&mut Struct>`.
 //!
-//! * #[dissolve(rename = "name")]
+//! * `#[dissolve(parts = "Name")]`
+//!   Declares a struct `Name` with the same fields as the struct, all `pub`, and returns it
+//!   from `dissolve` in place of a tuple. The fields can then be taken by name when
+//!   destructuring. `#[dissolve_ref(parts = "NameRef")]` and
+//!   `#[dissolve_mut(parts = "NameMut")]` declare structs of references instead, with a
+//!   lifetime ahead of the struct's own generics, eg; `NameRef<'a>`. Combined with
+//!   `impl_from` the `From` impls convert to the parts struct. Fields behind a `#[cfg(...)]`
+//!   keep it in the parts struct, so the methods are generated whichever fields are present.
+//!   Generic parameters that none of the dissolved fields use are held by a hidden
+//!   `PhantomData` field, so destructure the parts struct with `..` in that case.
 //!
 //! ```edition2018
 //! # use derive_getters::Dissolve;
 //! #[derive(Dissolve)]
-//! #[dissolve(rename = "shatter")]
+//! #[dissolve(rename = "shatter", vis = "pub(crate)")]
 //! struct Numbers {
 //!     a: u64,
 //!     b: i64,
@@ -148,28 +533,269 @@
 //! # fn main() { }
 //! ```
 //!
-//! # Panics
+//! ```edition2018
+//! # use derive_getters::{Dissolve, DissolveRef};
+//! #[derive(Dissolve, DissolveRef)]
+//! #[dissolve(impl_from)]
+//! #[dissolve_ref(impl_from)]
+//! struct Point {
+//!     x: i64,
+//!     y: i64,
+//! }
+//!
+//! let point = Point { x: 1, y: 2 };
+//! let (x, y): (&i64, &i64) = (&point).into();
+//! assert!(*x + *y == 3);
+//! let tuples: Vec<(i64, i64)> = vec![point].into_iter().map(Into::into).collect();
+//! assert!(tuples == [(1, 2)]);
+//! ```
+//!
+//! ```edition2018
+//! # use derive_getters::{Dissolve, DissolveMut};
+//! #[derive(Dissolve, DissolveMut)]
+//! #[dissolve(parts = "OrderParts")]
+//! #[dissolve_mut(parts = "OrderPartsMut")]
+//! struct Order {
+//!     id: u64,
+//!     total: f64,
+//!     lines: Vec<String>,
+//! }
+//!
+//! let mut order = Order { id: 7, total: 9.5, lines: Vec::new() };
+//! let OrderPartsMut { total, .. } = order.dissolve_mut();
+//! *total += 0.5;
+//! let OrderParts { id, total, .. } = order.dissolve();
+//! assert!(id == 7 && total == 10.0);
+//! ```
+//!
+//! Fields can be left out of the tuple, or moved within it, with field attributes.
+//!
+//! * `#[dissolve(skip)]`
+//!   Leaves the field out. `dissolve` drops it with the rest of the struct.
+//!
+//! * `#[dissolve(include)]`
+//!   Puts a `PhantomData` or `PhantomPinned` field in the tuple. These marker fields are
+//!   left out by default.
+//!
+//! * `#[dissolve(position = 0)]`
+//!   Puts the field at the given position in the tuple. Fields without a position fill the
+//!   remaining places in the order they were defined. Giving two fields the same position,
+//!   or a position past the end of the tuple, is a compile error.
+//!
+//! ```edition2018
+//! # use derive_getters::{Dissolve, DissolveRef};
+//! # use std::marker::PhantomData;
+//! #[derive(Dissolve, DissolveRef)]
+//! struct Connection<T> {
+//!     host: String,
+//!     #[dissolve(position = 0)]
+//!     port: u16,
+//!     #[dissolve(skip)]
+//!     #[dissolve_ref(skip)]
+//!     marker: PhantomData<T>,
+//! }
+//!
+//! let connection: Connection<()> = Connection {
+//!     host: "localhost".to_owned(),
+//!     port: 80,
+//!     marker: PhantomData,
+//! };
+//! let (host, port) = connection.dissolve_ref();
+//! assert!(host == "localhost" && *port == 80);
+//! let (port, host) = connection.dissolve();
+//! assert!(port == 80 && host == "localhost");
+//! ```
+//!
+//! `dissolve` moves each field out of the struct, which Rust doesn't allow for structs
+//! implementing `Drop`. Two struct options get the fields out another way.
+//!
+//! * `#[dissolve(skip_drop)]`
+//!   The struct's `Drop` impl is not run. Fields are read out of the struct with
+//!   `ManuallyDrop`, and skipped fields are dropped in place.
+//!
+//! * `#[dissolve(take)]`
+//!   Each field is swapped for its default with `std::mem::take`, so field types must
+//!   implement `Default`. The struct is then dropped as usual, its `Drop` impl seeing the
+//!   defaulted fields.
+//!
+//! The tuple itself can be reshaped.
+//!
+//! * `#[dissolve(unwrap_single)]`
+//!   Returns the field of a one field struct as it is, `T`, rather than as `(T,)`. It's a
+//!   compile error alongside `impl_from` when the field is a type parameter, since `From`
+//!   can't be implemented for one.
+//!
+//! * `#[dissolve(chunk = 12)]`
+//!   Splits tuples of more than 12 elements into nested tuples of up to 12 elements each,
+//!   eg; 14 fields give `((a, ..., l), (m, n))`. The standard library only implements
+//!   traits such as `Debug`, `PartialEq` and `Default` for tuples of up to 12 elements, so
+//!   this keeps the tuples of large structs usable. Any size of at least 2 can be given.
+//!
+//! Fields that may be unsized, such as `str`, slices, trait objects and `?Sized` type
+//! parameters, can't be moved out at all. `Dissolve` gives a compile error for them, but
+//! `DissolveRef` and `DissolveMut` can borrow them.
+//!
+//! ```edition2018
+//! # use derive_getters::Dissolve;
+//! #[derive(Dissolve)]
+//! #[dissolve(skip_drop)]
+//! struct Transaction {
+//!     id: u64,
+//!     statements: Vec<String>,
+//! }
+//!
+//! impl Drop for Transaction {
+//!     fn drop(&mut self) {
+//!         println!("rolling back {}", self.id);
+//!     }
+//! }
+//!
+//! let transaction = Transaction { id: 1, statements: vec!["SELECT 1".to_owned()] };
+//! let (id, statements) = transaction.dissolve();
+//! assert!(id == 1 && statements.len() == 1);
+//! ```
+//!
+//! # `Variants` methods generated
+//!
+//! Deriving `Variants` on an enum generates, for each variant, an `is_variant(&self)`
+//! method telling whether the enum is that variant. Variants with fields also get
+//! `as_variant(&self)`, `as_variant_mut(&mut self)` and `into_variant(self)` returning
+//! an `Option` of the fields. A variant with one field gives `Option<&T>`, `Option<&mut T>`
+//! and `Option<T>`, while one with several gives a tuple of them in the order they were
+//! defined, eg; `Option<(&A, &B)>`. Methods are named after the variant converted to snake
+//! case, so `TextMessage` gives `is_text_message()` and so on.
+//!
+//! * `#[variant(skip)]`
+//!   Will skip generating methods for the variant being decorated.
+//!
+//! * `#[variant(rename = "name")]`
+//!   Uses "name" in place of the snake case variant name, giving `is_name()`, `as_name()`,
+//!   `as_name_mut()` and `into_name()`.
+//!
+//! * `#[variant(vis = "pub(crate)")]`
+//!   As with getters, `"inherit"` gives the methods the same visibility as the enum.
+//!
+//! ```edition2018
+//! # use derive_getters::Variants;
+//! #[derive(Variants)]
+//! enum Shape {
+//!     Circle(f64),
+//!     Rectangle { width: f64, height: f64 },
+//!
+//!     #[variant(rename = "dot")]
+//!     Point,
+//! }
+//!
+//! let mut shape = Shape::Rectangle { width: 2.0, height: 3.0 };
+//! assert!(shape.is_rectangle());
+//! assert!(shape.as_circle().is_none());
+//! if let Some((width, _)) = shape.as_rectangle_mut() {
+//!     *width = 4.0;
+//! }
+//! assert!(shape.into_rectangle() == Some((4.0, 3.0)));
+//! assert!(Shape::Point.is_dot());
+//! ```
+//!
+//! # `New`
+//! Deriving `New` on a struct generates a `new` constructor taking the fields as arguments
+//! in the order `dissolve` returns them, so following any `#[dissolve(position = N)]`.
+//! Fields `dissolve` leaves out come last, in the order they were defined. It also
+//! implements `From` for a tuple of the fields `dissolve` returns, in the same order, so
+//! `Struct::from(value.dissolve())` gives back the value. A single field is taken as a one
+//! element tuple, `(T,)`. Fields `dissolve` leaves out are set with their
+//! `#[new(default)]` or `#[new(value = "...")]`, and markers are set to
+//! `Default::default()`. When a field left out by `dissolve` has neither there's nothing
+//! to set it to, so `From` isn't implemented. The `From` impl follows `unwrap_single` and
+//! `chunk`, and with `#[dissolve(parts = "...")]` is for the parts struct in place of a
+//! tuple.
+//!
+//! * `#[new(default)]`
+//!   The field is set to `Default::default()` instead of being an argument.
+//!
+//! * `#[new(value = "expr")]`
+//!   The field is set to the expression instead of being an argument.
+//!
+//! * `#[new(into)]`
+//!   The argument is `impl Into<T>` and gets converted.
 //!
-//! If `Getters` or `Dissolve` are derived on unit or unnamed structs, enums or unions.
+//! As with `dissolve`, the constructor can be renamed or have its visibility changed with
+//! `#[new(rename = "name")]` and `#[new(vis = "pub(crate)")]` on the struct.
 //!
-//! # Cannot Do
-//! Const generics aren't handled by this macro nor are they tested.
+//!```edition2018
+//! # use derive_getters::{Dissolve, New};
+//! #[derive(Dissolve, New)]
+//! struct Stuff {
+//!     #[new(into)]
+//!     name: String,
+//!     price: f64,
+//!
+//!     #[new(value = "1")]
+//!     count: usize,
+//! }
+//!
+//! let stuff = Stuff::new("Hogie", 123.4);
+//! let stuff = Stuff::from(stuff.dissolve());
+//! assert!(stuff.name == "Hogie" && stuff.count == 1);
+//! ```
+//!
+//! # `Homogeneous`
+//! A struct whose fields all have the same type, `T`, can derive `Homogeneous` to be used
+//! much like an array of its fields. It generates `into_array(self) -> [T; N]`,
+//! `as_array_refs(&self) -> [&T; N]`, `iter_fields(&self)` and `iter_fields_mut(&mut self)`,
+//! and implements `Index<usize>` and `IndexMut<usize>`. Indexing past the last field
+//! panics. Fields are taken in the same order `dissolve` returns them in, so the field
+//! attributes `#[homogeneous(skip)]`, `#[homogeneous(include)]` and
+//! `#[homogeneous(position = N)]` work as they do for `Dissolve`, and `PhantomData` fields
+//! are left out. Field types are compared as written, so a struct with fields of
+//! different types, or of the same type written differently, fails to compile. The
+//! visibility of the methods is set with `#[homogeneous(vis = "pub(crate)")]`.
+//!
+//!```edition2018
+//! # use derive_getters::Homogeneous;
+//! #[derive(Homogeneous)]
+//! struct Rgb {
+//!     r: u8,
+//!     g: u8,
+//!     b: u8,
+//! }
+//!
+//! let mut rgb = Rgb { r: 10, g: 20, b: 30 };
+//! rgb[1] += 5;
+//! for value in rgb.iter_fields_mut() {
+//!     *value /= 5;
+//! }
+//! assert!(rgb.iter_fields().map(|value| *value as u32).sum::<u32>() == 13);
+//! assert!(rgb.into_array() == [2, 5, 6]);
+//! ```
+//!
+//! # Panics
+//!
+//! If `Dissolve`, `DissolveRef`, `DissolveMut`, `MutGetters`, `Setters`, `With`, `New` or
+//! `Homogeneous` is derived on an enum or union, or `Variants` is derived on a struct or
+//! union.
 use std::convert::TryFrom;
 
 extern crate proc_macro;
 use syn::{DeriveInput, parse_macro_input};
 
 mod faultmsg;
+mod case;
 mod dissolve;
 mod dissolve_mut;
 mod dissolve_ref;
 mod getters;
+mod getters_mut;
+mod homogeneous;
+mod new;
+mod setters;
+mod variants;
+mod with;
 mod extract;
 
-/// Generate getter methods for all named struct fields in a seperate struct `impl` block.
+/// Generate getter methods for all struct fields in a seperate struct `impl` block.
 /// Getter methods share the name of the field they're 'getting'. Methods return an
-/// immutable reference to the field.
-#[proc_macro_derive(Getters, attributes(getter))]
+/// immutable reference to the field unless configured to copy or clone it instead.
+#[proc_macro_derive(Getters, attributes(getter, getters))]
 pub fn getters(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     
@@ -179,7 +805,58 @@ pub fn getters(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         .into()
 }
 
-/// Produce a `dissolve` method that consumes the named struct returning a tuple of all
+/// Generate mutable getter methods for each selected struct field. Tuple struct fields get
+/// methods named after their index, such as `_0_mut`, apart from a newtype's which is
+/// `inner_mut`.
+#[proc_macro_derive(MutGetters, attributes(getter_mut, getters_mut))]
+pub fn mut_getters(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    getters_mut::NamedStruct::try_from(&ast)
+        .map(|ns| ns.emit())
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Generate chainable setter methods for each struct field. Tuple struct fields get
+/// methods named after their index, such as `set_0`, apart from a newtype's which is
+/// `set_inner`.
+#[proc_macro_derive(Setters, attributes(setter, setters))]
+pub fn setters(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    setters::NamedStruct::try_from(&ast)
+        .map(|ns| ns.emit())
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Generate `with_` methods for each struct field, returning the struct with that field
+/// replaced. Tuple struct fields get methods named after their index, such as `with_0`,
+/// apart from a newtype's which is `with_inner`.
+#[proc_macro_derive(With, attributes(with))]
+pub fn with(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    with::NamedStruct::try_from(&ast)
+        .map(|ns| ns.emit())
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Produce a `new` constructor taking the struct fields in order, along with a `From`
+/// implementation for a tuple of every field.
+#[proc_macro_derive(New, attributes(new))]
+pub fn new(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    new::NamedStruct::try_from(&ast)
+        .map(|ns| ns.emit())
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Produce a `dissolve` method that consumes the struct returning a tuple of all
 /// the struct fields.
 #[proc_macro_derive(Dissolve, attributes(dissolve))]
 pub fn dissolve(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -191,7 +868,7 @@ pub fn dissolve(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         .into()
 }
 
-/// Produce a `dissolve_mut` method that mutably references the named struct returning a tuple of
+/// Produce a `dissolve_mut` method that mutably references the struct returning a tuple of
 /// mutable references to all the struct fields.
 #[proc_macro_derive(DissolveMut, attributes(dissolve_mut))]
 pub fn dissolve_mut(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -203,7 +880,7 @@ pub fn dissolve_mut(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         .into()
 }
 
-/// Produce a `dissolve_ref` method that references the named struct returning a tuple of
+/// Produce a `dissolve_ref` method that references the struct returning a tuple of
 /// references to all the struct fields.
 #[proc_macro_derive(DissolveRef, attributes(dissolve_ref))]
 pub fn dissolve_ref(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -215,3 +892,25 @@ pub fn dissolve_ref(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         .into()
 }
 
+/// Produce `into_array`, `as_array_refs`, `iter_fields` and `iter_fields_mut` methods, and
+/// `Index` and `IndexMut` impls, for a struct whose fields all have the same type.
+#[proc_macro_derive(Homogeneous, attributes(homogeneous))]
+pub fn homogeneous(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    homogeneous::NamedStruct::try_from(&ast)
+        .map(|ns| ns.emit())
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+/// Produce `is_`, `as_`, `as_..._mut` and `into_` methods for each variant of an enum.
+#[proc_macro_derive(Variants, attributes(variant))]
+pub fn variants(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    variants::NamedEnum::try_from(&ast)
+        .map(|ne| ne.emit())
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
@@ -0,0 +1,394 @@
+//! New internals
+use std::convert::TryFrom;
+
+use proc_macro2::{TokenStream, Span};
+use quote::quote;
+use syn::{
+    DeriveInput,
+    DataStruct,
+    Member,
+    Type,
+    Expr,
+    Ident,
+    Result,
+    Error,
+    AttrStyle,
+    LitStr,
+    Attribute,
+    Visibility,
+    punctuated::Punctuated,
+    parse::{Parse, ParseStream},
+};
+
+use crate::{
+    extract::{
+        named_struct,
+        field_members,
+        member_name,
+        end_of_option,
+        cfg_attributes,
+        cfg_gate,
+        is_marker,
+        parts_member,
+        Vis,
+    },
+    faultmsg::Problem,
+    dissolve::{selected_fields, returned_as, Shape},
+};
+
+/// Where `new` gets the value of a field from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Source {
+    /// An argument, converted with `Into` when `into` is set.
+    Argument { into: bool },
+    /// `Default::default()`.
+    Default,
+    /// An expression given with `value = "..."`.
+    Value(Box<Expr>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum FieldAction {
+    Default,
+    Into,
+    Value(Box<Expr>),
+}
+
+impl Parse for FieldAction {
+    fn parse(input: ParseStream) -> Result<Self> {
+        syn::custom_keyword!(into);
+        syn::custom_keyword!(value);
+
+        if input.peek(syn::Token![default]) {
+            let _ = input.parse::<syn::Token![default]>()?;
+            Ok(FieldAction::Default)
+        } else if input.peek(into) {
+            let _ = input.parse::<into>()?;
+            Ok(FieldAction::Into)
+        } else if input.peek(value) {
+            let _ = input.parse::<value>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let expr = input.parse::<LitStr>()?;
+            Ok(FieldAction::Value(Box::new(expr.parse()?)))
+        } else {
+            Err(Error::new(Span::call_site(), Problem::InvalidAttribute))
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Action {
+    Rename(Ident),
+    Vis(Vis),
+}
+
+impl Parse for Action {
+    fn parse(input: ParseStream) -> Result<Self> {
+        syn::custom_keyword!(rename);
+        syn::custom_keyword!(vis);
+
+        if input.peek(rename) {
+            let _ = input.parse::<rename>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let name = input.parse::<LitStr>()?;
+            if !end_of_option(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowNewName))
+            } else {
+                let name = Ident::new(name.value().as_str(), Span::call_site());
+                Ok(Action::Rename(name))
+            }
+        } else if input.peek(vis) {
+            Ok(Action::Vis(input.parse()?))
+        } else {
+            Err(Error::new(Span::call_site(), Problem::InvalidAttribute))
+        }
+    }
+}
+
+fn new_actions_from<T: Parse>(attributes: &[Attribute]) -> Result<Vec<T>> {
+    let mut current: Vec<T> = Vec::new();
+
+    for attr in attributes {
+        if attr.style != AttrStyle::Outer { continue; }
+
+        if attr.path.is_ident("new") {
+            let actions = attr.parse_args_with(
+                Punctuated::<T, syn::Token![,]>::parse_terminated
+            )?;
+            current.extend(actions);
+        }
+    }
+
+    Ok(current)
+}
+
+pub struct Field {
+    ty: Type,
+    member: Member,
+    argument: Ident,
+    source: Source,
+    cfgs: Vec<Attribute>,
+}
+
+impl Field {
+    fn from_field(member: Member, field: &syn::Field) -> Result<Self> {
+        let mut source = Source::Argument { into: false };
+        for action in new_actions_from::<FieldAction>(field.attrs.as_slice())? {
+            source = match action {
+                FieldAction::Default => Source::Default,
+                FieldAction::Into => Source::Argument { into: true },
+                FieldAction::Value(expr) => Source::Value(expr),
+            };
+        }
+
+        // Named fields give their name to the argument, tuple fields are numbered.
+        let argument = match member {
+            Member::Named(ref ident) => ident.clone(),
+            Member::Unnamed(ref index) => Ident::new(
+                format!("field_{}", index.index).as_str(),
+                Span::call_site(),
+            ),
+        };
+
+        Ok(Field {
+            ty: field.ty.clone(),
+            member,
+            argument,
+            source,
+            cfgs: cfg_attributes(field.attrs.as_slice()),
+        })
+    }
+
+    fn from_fields(structure: &DataStruct) -> Result<Vec<Self>> {
+        field_members(&structure.fields)
+            .into_iter()
+            .map(|(member, field)| Field::from_field(member, field))
+            .collect()
+    }
+
+    /// The parameter of `new` taking this field, if it takes one.
+    fn parameter(&self) -> Option<TokenStream> {
+        let ty = &self.ty;
+        let argument = &self.argument;
+        let cfgs = &self.cfgs;
+
+        match self.source {
+            Source::Argument { into: false } => Some(quote!(#(#cfgs)* #argument: #ty)),
+            Source::Argument { into: true } => Some(
+                quote!(#(#cfgs)* #argument: impl ::std::convert::Into<#ty>)
+            ),
+            Source::Default | Source::Value(_) => None,
+        }
+    }
+
+    /// The field in the struct expression built by `new`.
+    fn initialiser(&self) -> TokenStream {
+        let member = &self.member;
+        let argument = &self.argument;
+        let cfgs = &self.cfgs;
+
+        let value = match self.source {
+            Source::Argument { into: false } => quote!(#argument),
+            Source::Argument { into: true } => quote!(#argument.into()),
+            Source::Default => quote!(::std::default::Default::default()),
+            Source::Value(ref expr) => quote!(#expr),
+        };
+
+        quote!(#(#cfgs)* #member: #value)
+    }
+
+    /// The field in the struct expression built by `From` when the tuple leaves it out.
+    /// It's set as `new` sets it, or to its default if it's a marker. Other fields `new`
+    /// takes as arguments have nothing to be set to, so there's no `From` impl.
+    fn filler(&self) -> Option<TokenStream> {
+        let member = &self.member;
+        let cfgs = &self.cfgs;
+
+        let value = match self.source {
+            Source::Value(ref expr) => quote!(#expr),
+            Source::Default => quote!(::std::default::Default::default()),
+            Source::Argument { .. } if is_marker(&self.ty) => {
+                quote!(::std::default::Default::default())
+            },
+            Source::Argument { .. } => return None,
+        };
+
+        Some(quote!(#(#cfgs)* #member: #value))
+    }
+}
+
+pub struct NamedStruct<'a> {
+    original: &'a DeriveInput,
+    name: Ident,
+    fields: Vec<Field>,
+    from_members: Vec<Member>,
+    shape: Shape,
+    parts: Option<Ident>,
+    new_rename: Option<Ident>,
+    vis: Visibility,
+    cfg: TokenStream,
+}
+
+impl<'a> NamedStruct<'a> {
+    pub fn emit(&self) -> TokenStream {
+        let (impl_generics, struct_generics, where_clause) = self.original.generics
+            .split_for_impl();
+        let struct_name = &self.name;
+        let vis = &self.vis;
+        let cfg = &self.cfg;
+
+        let new = Ident::new("new", Span::call_site());
+        let fn_name = self.new_rename
+            .as_ref()
+            .unwrap_or(&new);
+
+        // Arguments come in the order `dissolve` returns the fields, followed by any it
+        // leaves out in the order they were declared.
+        let mut arguments: Vec<&Field> = self.fields
+            .iter()
+            .filter(|field| matches!(field.source, Source::Argument { .. }))
+            .collect();
+        arguments.sort_by_key(|field| {
+            self.from_members
+                .iter()
+                .position(|member| *member == field.member)
+                .unwrap_or(self.from_members.len())
+        });
+        let parameters = arguments.iter().filter_map(|field| field.parameter());
+        let initialisers = self.fields.iter().map(|field| field.initialiser());
+
+        let order = arguments
+            .iter()
+            .map(|field| member_name(&field.member))
+            .collect::<Vec<String>>()
+            .join(", ");
+        let doc = format!(
+            "Creates a `{}` from its fields, taken in the order `({})`.",
+            struct_name,
+            order,
+        );
+
+        // `From` takes the fields `dissolve` returns, in the same order and shape, so it's
+        // the inverse of `dissolve`.
+        let from_fields: Vec<&Field> = self.from_members
+            .iter()
+            .filter_map(|member| self.fields.iter().find(|field| field.member == *member))
+            .collect();
+        let bindings: Vec<Ident> = (0..from_fields.len())
+            .map(|i| Ident::new(format!("field_{}", i).as_str(), Span::mixed_site()))
+            .collect();
+        let from_initialisers: Option<Vec<TokenStream>> = self.fields
+            .iter()
+            .map(|field| {
+                match self.from_members.iter().position(|member| *member == field.member) {
+                    Some(index) => {
+                        let member = &field.member;
+                        let cfgs = &field.cfgs;
+                        let binding = &bindings[index];
+                        Some(quote!(#(#cfgs)* #member: #binding))
+                    },
+                    None => field.filler(),
+                }
+            })
+            .collect();
+        let (source, pattern) = match self.parts {
+            Some(ref parts_name) => {
+                let parts = from_fields.iter().zip(bindings.iter()).enumerate().map(
+                    |(index, (field, binding))| {
+                        let part = parts_member(&field.member, index);
+                        let cfgs = &field.cfgs;
+                        quote!(#(#cfgs)* #part: #binding)
+                    }
+                );
+                (
+                    quote!(#parts_name #struct_generics),
+                    quote!(#parts_name { #(#parts,)* .. }),
+                )
+            },
+            None => (
+                self.shape.tuple(from_fields.iter().map(|field| {
+                    let ty = &field.ty;
+                    quote!(#ty)
+                }).collect()),
+                self.shape.tuple(bindings.iter().map(|binding| quote!(#binding)).collect()),
+            ),
+        };
+        let value = Ident::new("value", Span::mixed_site());
+
+        let from = match from_initialisers {
+            Some(initialisers) => quote!(
+                #cfg
+                impl #impl_generics ::std::convert::From<#source>
+                    for #struct_name #struct_generics
+                    #where_clause
+                {
+                    fn from(#value: #source) -> Self {
+                        let #pattern = #value;
+                        Self {
+                            #(#initialisers),*
+                        }
+                    }
+                }
+            ),
+            None => TokenStream::new(),
+        };
+
+        quote!(
+            impl #impl_generics #struct_name #struct_generics
+                #where_clause
+            {
+                #[doc = #doc]
+                #vis fn #fn_name(#(#parameters),*) -> Self {
+                    Self {
+                        #(#initialisers),*
+                    }
+                }
+            }
+
+            #from
+        )
+    }
+}
+
+impl<'a> TryFrom<&'a DeriveInput> for NamedStruct<'a> {
+    type Error = Error;
+
+    fn try_from(node: &'a DeriveInput) -> Result<Self> {
+        let struct_data = named_struct(node)?;
+        let fields = Field::from_fields(struct_data)?;
+        let selected = selected_fields(struct_data, "dissolve")?;
+        let (shape, parts) = returned_as(node)?;
+
+        // As with `dissolve`, the tuple taken by `From` needs every field in it to exist.
+        // A parts struct carries the `cfg` of each field instead.
+        let cfgs: Vec<Attribute> = selected
+            .iter()
+            .flat_map(|(_, field)| cfg_attributes(field.attrs.as_slice()))
+            .collect();
+        let cfg = match parts {
+            Some(_) => TokenStream::new(),
+            None => cfg_gate(cfgs.as_slice())?,
+        };
+
+        let mut rename = None;
+        let mut vis = Vis::default();
+        for action in new_actions_from::<Action>(node.attrs.as_slice())? {
+            match action {
+                Action::Rename(name) => rename = Some(name),
+                Action::Vis(v) => vis = v,
+            }
+        }
+
+        Ok(NamedStruct {
+            original: node,
+            name: node.ident.clone(),
+            fields,
+            from_members: selected.into_iter().map(|(member, _)| member).collect(),
+            shape,
+            parts,
+            new_rename: rename,
+            vis: vis.resolve(&node.vis),
+            cfg,
+        })
+    }
+}
@@ -0,0 +1,404 @@
+//! Setters internals
+use std::convert::TryFrom;
+
+use proc_macro2::{TokenStream, Span};
+use quote::quote;
+use syn::{
+    DeriveInput,
+    DataStruct,
+    Member,
+    Type,
+    AttrStyle,
+    Ident,
+    LitStr,
+    Path,
+    Result,
+    Error,
+    Attribute,
+    Visibility,
+    punctuated::Punctuated,
+    parse::{Parse, ParseStream},
+};
+
+use crate::{
+    extract::{
+        named_struct,
+        field_members,
+        is_newtype,
+        member_name,
+        end_of_option,
+        cfg_attributes,
+        Vis,
+    },
+    faultmsg::Problem,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Action {
+    Skip,
+    Rename(Ident),
+    Into,
+    Vis(Vis),
+    Validate(Path),
+    Error(Box<Type>),
+}
+
+fn parse_into(input: ParseStream) -> Result<bool> {
+    syn::custom_keyword!(into);
+
+    if input.peek(into) {
+        let _ = input.parse::<into>()?;
+        Ok(true)
+    } else {
+        Ok(false)
+    }
+}
+
+fn parse_error(input: ParseStream) -> Result<Option<Box<Type>>> {
+    syn::custom_keyword!(error);
+
+    if input.peek(error) {
+        let _ = input.parse::<error>()?;
+        let _ = input.parse::<syn::Token![=]>()?;
+        Ok(Some(Box::new(input.parse::<LitStr>()?.parse()?)))
+    } else {
+        Ok(None)
+    }
+}
+
+impl Parse for Action {
+    fn parse(input: ParseStream) -> Result<Self> {
+        syn::custom_keyword!(skip);
+        syn::custom_keyword!(rename);
+        syn::custom_keyword!(vis);
+        syn::custom_keyword!(validate);
+
+        if input.peek(skip) {
+            let _ = input.parse::<skip>()?;
+            if !end_of_option(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowSkip))
+            } else {
+                Ok(Action::Skip)
+            }
+        } else if input.peek(rename) {
+            let _ = input.parse::<rename>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let name = input.parse::<LitStr>()?;
+            if !end_of_option(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowNewName))
+            } else {
+                Ok(Action::Rename(Ident::new(name.value().as_str(), Span::call_site())))
+            }
+        } else if parse_into(input)? {
+            Ok(Action::Into)
+        } else if input.peek(vis) {
+            Ok(Action::Vis(input.parse()?))
+        } else if input.peek(validate) {
+            let _ = input.parse::<validate>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            Ok(Action::Validate(input.parse::<LitStr>()?.parse()?))
+        } else if let Some(error) = parse_error(input)? {
+            Ok(Action::Error(error))
+        } else {
+            Err(Error::new(Span::call_site(), Problem::InvalidAttribute))
+        }
+    }
+}
+
+fn setter_actions_from(attributes: &[Attribute]) -> Result<Vec<Action>> {
+    let mut current: Vec<Action> = Vec::new();
+
+    for attr in attributes {
+        if attr.style != AttrStyle::Outer { continue; }
+
+        if attr.path.is_ident("setter") {
+            let actions = attr.parse_args_with(
+                Punctuated::<Action, syn::Token![,]>::parse_terminated
+            )?;
+            current.extend(actions);
+        }
+    }
+
+    Ok(current)
+}
+
+/// Struct wide options set with `#[setters(...)]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum StructAction {
+    Into,
+    Vis(Vis),
+    Error(Box<Type>),
+}
+
+impl Parse for StructAction {
+    fn parse(input: ParseStream) -> Result<Self> {
+        syn::custom_keyword!(vis);
+
+        if parse_into(input)? {
+            Ok(StructAction::Into)
+        } else if input.peek(vis) {
+            Ok(StructAction::Vis(input.parse()?))
+        } else if let Some(error) = parse_error(input)? {
+            Ok(StructAction::Error(error))
+        } else {
+            Err(Error::new(Span::call_site(), Problem::InvalidAttribute))
+        }
+    }
+}
+
+/// Defaults applied to every field unless the field says otherwise.
+#[derive(Debug, Default)]
+struct Config {
+    into: bool,
+    vis: Vis,
+    error: Option<Box<Type>>,
+}
+
+impl Config {
+    fn from_attributes(attributes: &[Attribute]) -> Result<Self> {
+        let mut config = Config::default();
+
+        for attr in attributes {
+            if attr.style != AttrStyle::Outer { continue; }
+
+            if attr.path.is_ident("setters") {
+                let actions = attr.parse_args_with(
+                    Punctuated::<StructAction, syn::Token![,]>::parse_terminated
+                )?;
+                for action in actions {
+                    match action {
+                        StructAction::Into => config.into = true,
+                        StructAction::Vis(vis) => config.vis = vis,
+                        StructAction::Error(error) => config.error = Some(error),
+                    }
+                }
+            }
+        }
+
+        Ok(config)
+    }
+}
+
+/// Name of the setter for a field without a `rename` of its own. Tuple struct fields are
+/// set by index, `set_0`, `set_1` and so on, apart from a newtype's which is `set_inner`.
+fn setter_name(member: &Member, newtype: bool) -> Ident {
+    let base = match member {
+        Member::Unnamed(_) if newtype => "inner".to_owned(),
+        _ => member_name(member),
+    };
+
+    Ident::new(format!("set_{}", base).as_str(), Span::call_site())
+}
+
+/// A validator called on the new value before it's set, and the error type the setter
+/// returns when it fails.
+struct Validation {
+    validator: Path,
+    error: Box<Type>,
+}
+
+pub struct Field {
+    ty: Type,
+    member: Member,
+    setter: Ident,
+    into: bool,
+    vis: Visibility,
+    cfgs: Vec<Attribute>,
+    validation: Option<Validation>,
+}
+
+impl Field {
+    fn from_field(
+        member: Member,
+        field: &syn::Field,
+        newtype: bool,
+        config: &Config,
+    ) -> Result<Option<Self>> {
+        let mut skip = false;
+        let mut setter = setter_name(&member, newtype);
+        let mut into = config.into;
+        let mut vis = config.vis.clone();
+        let mut validator: Option<Path> = None;
+        let mut error = config.error.clone();
+
+        for action in setter_actions_from(field.attrs.as_slice())? {
+            match action {
+                Action::Skip => skip = true,
+                Action::Rename(ident) => {
+                    skip = false;
+                    setter = ident;
+                },
+                Action::Into => into = true,
+                Action::Vis(v) => vis = v,
+                Action::Validate(path) => validator = Some(path),
+                Action::Error(ty) => error = Some(ty),
+            }
+        }
+
+        if skip {
+            return Ok(None);
+        }
+
+        let validation = match (validator, error) {
+            (Some(validator), Some(error)) => Some(Validation { validator, error }),
+            (Some(_), None) => return Err(
+                Error::new_spanned(field, Problem::ValidateWithoutError)
+            ),
+            (None, _) => None,
+        };
+
+        Ok(Some(Field {
+            ty: field.ty.clone(),
+            member,
+            setter,
+            into,
+            vis: vis.resolve(&field.vis),
+            cfgs: cfg_attributes(field.attrs.as_slice()),
+            validation,
+        }))
+    }
+
+    fn from_fields(structure: &DataStruct, config: &Config) -> Result<Vec<Self>> {
+        let newtype = is_newtype(structure);
+
+        field_members(&structure.fields)
+            .into_iter()
+            .try_fold(Vec::new(), |mut fields, (member, field)| {
+                if let Some(field) = Field::from_field(member, field, newtype, config)? {
+                    fields.push(field);
+                }
+
+                Ok(fields)
+            })
+    }
+
+    fn emit(&self) -> TokenStream {
+        let ty = &self.ty;
+        let member = &self.member;
+        let setter_name = &self.setter;
+        let vis = &self.vis;
+        let cfgs = &self.cfgs;
+        let value = Ident::new("value", Span::mixed_site());
+
+        let (argument, conversion) = if self.into {
+            (quote!(impl ::std::convert::Into<#ty>), quote!(let #value: #ty = #value.into();))
+        } else {
+            (quote!(#ty), TokenStream::new())
+        };
+
+        let doc = format!("Sets the `{}` field.", member_name(member));
+
+        // An explicit rename can give a name outside of snake case.
+        let allow_case = if setter_name.to_string().chars().any(char::is_uppercase) {
+            quote!(#[allow(non_snake_case)])
+        } else {
+            TokenStream::new()
+        };
+
+        let (returns, body, validation_doc) = match self.validation {
+            Some(Validation { ref validator, ref error }) => {
+                let validation_doc = format!(
+                    "The value is first checked with `{}`, returning its error without \
+                     setting the field if it fails.",
+                    quote!(#validator).to_string().replace(' ', ""),
+                );
+                (
+                    quote!(::std::result::Result<&mut Self, #error>),
+                    quote!(
+                        #validator(&#value)?;
+                        self.#member = #value;
+                        ::std::result::Result::Ok(self)
+                    ),
+                    quote!(
+                        #[doc = ""]
+                        #[doc = #validation_doc]
+                    ),
+                )
+            },
+            None => (
+                quote!(&mut Self),
+                quote!(
+                    self.#member = #value;
+                    self
+                ),
+                TokenStream::new(),
+            ),
+        };
+
+        quote!(
+            #(#cfgs)*
+            #[doc = #doc]
+            #validation_doc
+            #allow_case
+            #vis fn #setter_name(&mut self, #value: #argument) -> #returns {
+                #conversion
+                #body
+            }
+        )
+    }
+}
+
+pub struct NamedStruct<'a> {
+    original: &'a DeriveInput,
+    name: Ident,
+    fields: Vec<Field>,
+}
+
+impl<'a> NamedStruct<'a> {
+    pub fn emit(&self) -> TokenStream {
+        let (impl_generics, struct_generics, where_clause) = self.original.generics
+            .split_for_impl();
+        let struct_name = &self.name;
+        let methods = self.fields.iter().map(|field| field.emit());
+
+        quote!(
+            impl #impl_generics #struct_name #struct_generics
+                #where_clause
+            {
+                #(#methods)*
+            }
+        )
+    }
+}
+
+impl<'a> TryFrom<&'a DeriveInput> for NamedStruct<'a> {
+    type Error = Error;
+
+    fn try_from(node: &'a DeriveInput) -> Result<Self> {
+        let config = Config::from_attributes(node.attrs.as_slice())?;
+        let struct_data = named_struct(node)?;
+        let fields = Field::from_fields(struct_data, &config)?;
+
+        Ok(NamedStruct {
+            original: node,
+            name: node.ident.clone(),
+            fields,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_action() -> Result<()> {
+        let a: Action = syn::parse_str("into")?;
+        assert!(a == Action::Into);
+
+        let a: Action = syn::parse_str("validate = \"checks::port\"")?;
+        assert!(a == Action::Validate(syn::parse_quote!(checks::port)));
+
+        let a: Action = syn::parse_str("error = \"Box<dyn std::error::Error>\"")?;
+        let check = Action::Error(Box::new(syn::parse_quote!(Box<dyn std::error::Error>)));
+        assert!(a == check);
+
+        let r: Result<Action> = syn::parse_str("validate = check_port");
+        assert!(r.is_err());
+
+        let r: Result<Action> = syn::parse_str("copy");
+        assert!(r.is_err());
+
+        Ok(())
+    }
+}
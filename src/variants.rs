@@ -0,0 +1,300 @@
+//! Variants internals
+use std::convert::TryFrom;
+
+use proc_macro2::{TokenStream, Span};
+use quote::quote;
+use syn::{
+    DeriveInput,
+    Type,
+    Ident,
+    Result,
+    Error,
+    AttrStyle,
+    LitStr,
+    Attribute,
+    Member,
+    Visibility,
+    ext::IdentExt,
+    punctuated::Punctuated,
+    parse::{Parse, ParseStream},
+};
+
+use crate::{
+    case::RenameRule,
+    extract::{enum_data, field_members, end_of_option, cfg_attributes, Vis},
+    faultmsg::Problem,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Action {
+    Skip,
+    Rename(LitStr),
+    Vis(Vis),
+}
+
+impl Parse for Action {
+    fn parse(input: ParseStream) -> Result<Self> {
+        syn::custom_keyword!(skip);
+        syn::custom_keyword!(rename);
+        syn::custom_keyword!(vis);
+
+        if input.peek(skip) {
+            let _ = input.parse::<skip>()?;
+            if !end_of_option(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowSkip))
+            } else {
+                Ok(Action::Skip)
+            }
+        } else if input.peek(rename) {
+            let _ = input.parse::<rename>()?;
+            let _ = input.parse::<syn::Token![=]>()?;
+            let name = input.parse::<LitStr>()?;
+            if !end_of_option(input) {
+                Err(Error::new(Span::call_site(), Problem::TokensFollowNewName))
+            } else {
+                Ok(Action::Rename(name))
+            }
+        } else if input.peek(vis) {
+            Ok(Action::Vis(input.parse()?))
+        } else {
+            Err(Error::new(Span::call_site(), Problem::InvalidAttribute))
+        }
+    }
+}
+
+fn variant_actions_from(attributes: &[Attribute]) -> Result<Vec<Action>> {
+    let mut current: Vec<Action> = Vec::new();
+
+    for attr in attributes {
+        if attr.style != AttrStyle::Outer { continue; }
+
+        if attr.path.is_ident("variant") {
+            let actions = attr.parse_args_with(
+                Punctuated::<Action, syn::Token![,]>::parse_terminated
+            )?;
+            current.extend(actions);
+        }
+    }
+
+    Ok(current)
+}
+
+pub struct Variant {
+    ident: Ident,
+    is_fn: Ident,
+    as_fn: Ident,
+    as_mut_fn: Ident,
+    into_fn: Ident,
+    vis: Visibility,
+    cfgs: Vec<Attribute>,
+    fields: Vec<(Member, Type)>,
+}
+
+impl Variant {
+    fn from_variant(variant: &syn::Variant, inherited: &Visibility) -> Result<Option<Self>> {
+        let mut skip = false;
+        let mut name = RenameRule::Snake.apply(variant.ident.unraw().to_string().as_str());
+        let mut span = variant.ident.span();
+        let mut vis = Vis::default();
+
+        for action in variant_actions_from(variant.attrs.as_slice())? {
+            match action {
+                Action::Skip => skip = true,
+                Action::Rename(lit) => {
+                    skip = false;
+                    name = lit.value();
+                    span = lit.span();
+                },
+                Action::Vis(v) => vis = v,
+            }
+        }
+
+        if skip {
+            return Ok(None);
+        }
+
+        // The name comes from a rename or the variant, so that's where a bad one is blamed.
+        let method = |prefix: &str, suffix: &str| -> Result<Ident> {
+            let full = format!("{}{}{}", prefix, name, suffix);
+            syn::parse_str::<Ident>(full.as_str())
+                .map_err(|_| Error::new(span, Problem::InvalidMethodName(full)))
+        };
+
+        let fields = field_members(&variant.fields)
+            .into_iter()
+            .map(|(member, field)| (member, field.ty.clone()))
+            .collect();
+
+        Ok(Some(Variant {
+            ident: variant.ident.clone(),
+            is_fn: method("is_", "")?,
+            as_fn: method("as_", "")?,
+            as_mut_fn: method("as_", "_mut")?,
+            into_fn: method("into_", "")?,
+            vis: vis.resolve(inherited),
+            cfgs: cfg_attributes(variant.attrs.as_slice()),
+            fields,
+        }))
+    }
+
+    /// Pattern matching this variant, binding each field to `field_0`, `field_1` and so on.
+    fn pattern(&self) -> (TokenStream, Vec<Ident>) {
+        let ident = &self.ident;
+        let bindings: Vec<Ident> = (0..self.fields.len())
+            .map(|i| Ident::new(format!("field_{}", i).as_str(), Span::mixed_site()))
+            .collect();
+        let members = self.fields.iter().map(|(member, _)| member);
+
+        let pattern = quote!(Self::#ident { #(#members: #bindings),* });
+        (pattern, bindings)
+    }
+
+    fn emit(&self, enum_name: &Ident, exhaustive: bool) -> TokenStream {
+        let vis = &self.vis;
+        let cfgs = &self.cfgs;
+        let ident = &self.ident;
+        let otherwise = |value: TokenStream| if exhaustive {
+            TokenStream::new()
+        } else {
+            quote!(_ => #value,)
+        };
+
+        let is_fn = &self.is_fn;
+        let is_doc = format!("Returns `true` if this is a [`{}::{}`].", enum_name, ident);
+        let not_is = otherwise(quote!(false));
+        let is = quote!(
+            #(#cfgs)*
+            #[doc = #is_doc]
+            #vis fn #is_fn(&self) -> bool {
+                match self {
+                    Self::#ident { .. } => true,
+                    #not_is
+                }
+            }
+        );
+
+        // Variants without fields have nothing to hand back.
+        if self.fields.is_empty() {
+            return is;
+        }
+
+        let (pattern, bindings) = self.pattern();
+        let types: Vec<&Type> = self.fields.iter().map(|(_, ty)| ty).collect();
+        let (refs, muts, owned, values) = if self.fields.len() == 1 {
+            let ty = types[0];
+            let binding = &bindings[0];
+            (quote!(&#ty), quote!(&mut #ty), quote!(#ty), quote!(#binding))
+        } else {
+            (
+                quote!((#(&#types),*)),
+                quote!((#(&mut #types),*)),
+                quote!((#(#types),*)),
+                quote!((#(#bindings),*)),
+            )
+        };
+        let none = otherwise(quote!(None));
+
+        let as_fn = &self.as_fn;
+        let as_mut_fn = &self.as_mut_fn;
+        let into_fn = &self.into_fn;
+        let as_doc = format!(
+            "Returns references to the fields of a [`{}::{}`], or `None` for other variants.",
+            enum_name,
+            ident,
+        );
+        let as_mut_doc = format!(
+            "Returns mutable references to the fields of a [`{}::{}`], or `None` for other \
+             variants.",
+            enum_name,
+            ident,
+        );
+        let into_doc = format!(
+            "Consumes the enum returning the fields of a [`{}::{}`], or `None` for other \
+             variants.",
+            enum_name,
+            ident,
+        );
+
+        quote!(
+            #is
+
+            #(#cfgs)*
+            #[doc = #as_doc]
+            #vis fn #as_fn(&self) -> Option<#refs> {
+                match self {
+                    #pattern => Some(#values),
+                    #none
+                }
+            }
+
+            #(#cfgs)*
+            #[doc = #as_mut_doc]
+            #vis fn #as_mut_fn(&mut self) -> Option<#muts> {
+                match self {
+                    #pattern => Some(#values),
+                    #none
+                }
+            }
+
+            #(#cfgs)*
+            #[doc = #into_doc]
+            #vis fn #into_fn(self) -> Option<#owned> {
+                match self {
+                    #pattern => Some(#values),
+                    #none
+                }
+            }
+        )
+    }
+}
+
+pub struct NamedEnum<'a> {
+    original: &'a DeriveInput,
+    name: Ident,
+    variants: Vec<Variant>,
+    count: usize,
+}
+
+impl<'a> NamedEnum<'a> {
+    pub fn emit(&self) -> TokenStream {
+        let (impl_generics, enum_generics, where_clause) = self.original.generics
+            .split_for_impl();
+        let enum_name = &self.name;
+        let exhaustive = self.count == 1;
+        let methods = self.variants
+            .iter()
+            .map(|variant| variant.emit(enum_name, exhaustive));
+
+        quote!(
+            impl #impl_generics #enum_name #enum_generics
+                #where_clause
+            {
+                #(#methods)*
+            }
+        )
+    }
+}
+
+impl<'a> TryFrom<&'a DeriveInput> for NamedEnum<'a> {
+    type Error = Error;
+
+    fn try_from(node: &'a DeriveInput) -> Result<Self> {
+        let enum_data = enum_data(node)?;
+        let variants = enum_data.variants
+            .iter()
+            .try_fold(Vec::new(), |mut variants, variant| {
+                if let Some(variant) = Variant::from_variant(variant, &node.vis)? {
+                    variants.push(variant);
+                }
+
+                Ok::<_, Error>(variants)
+            })?;
+
+        Ok(NamedEnum {
+            original: node,
+            name: node.ident.clone(),
+            variants,
+            count: enum_data.variants.len(),
+        })
+    }
+}
@@ -0,0 +1,54 @@
+//! With internals
+use std::convert::TryFrom;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    DeriveInput,
+    Ident,
+    Result,
+    Error,
+};
+
+use crate::{
+    extract::named_struct,
+    getters::{Config, Field},
+};
+
+pub struct NamedStruct<'a> {
+    original: &'a DeriveInput,
+    name: Ident,
+    fields: Vec<Field>,
+}
+
+impl<'a> NamedStruct<'a> {
+    pub fn emit(&self) -> TokenStream {
+        let (impl_generics, struct_generics, where_clause) = self.original.generics
+            .split_for_impl();
+        let struct_name = &self.name;
+        let methods = self.fields.iter().map(|field| field.emit_with());
+
+        quote!(
+            impl #impl_generics #struct_name #struct_generics
+                #where_clause
+            {
+                #(#methods)*
+            }
+        )
+    }
+}
+
+impl<'a> TryFrom<&'a DeriveInput> for NamedStruct<'a> {
+    type Error = Error;
+
+    fn try_from(node: &'a DeriveInput) -> Result<Self> {
+        let struct_data = named_struct(node)?;
+        let fields = Field::from_fields(struct_data, &Config::for_with())?;
+
+        Ok(NamedStruct {
+            original: node,
+            name: node.ident.clone(),
+            fields,
+        })
+    }
+}
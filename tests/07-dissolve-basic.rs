@@ -43,15 +43,15 @@ impl LotsOfStuff {
 fn main() {
     let mut n = Number { num: 64 };
 
-    let number_mut = n.dissolve_mut();
+    let (number_mut,) = n.dissolve_mut();
     *number_mut += 1;
     assert!(*number_mut == 65);
     *number_mut -= 1;
     
-    let number_ref = n.dissolve_ref();
+    let (number_ref,) = n.dissolve_ref();
     assert!(*number_ref == 64);
     
-    let number = n.dissolve();
+    let (number,) = n.dissolve();
     assert!(number == 64);
 
     let inner = Inner { a: 22, b: -33 };
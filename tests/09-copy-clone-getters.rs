@@ -0,0 +1,90 @@
+//! Getters that return by value instead of by reference.
+
+use std::rc::Rc;
+use derive_getters::Getters;
+
+#[derive(Getters)]
+struct Point {
+    #[getter(copy)]
+    x: i64,
+
+    #[getter(copy)]
+    y: i64,
+
+    z: i64,
+}
+
+#[derive(Getters)]
+struct Shared {
+    #[getter(clone)]
+    name: Rc<String>,
+
+    #[getter(clone, rename = "label")]
+    tag: String,
+}
+
+#[derive(Getters)]
+#[getters(copy)]
+struct AllCopy<'a, T: Copy> {
+    a: u8,
+    b: f64,
+    c: T,
+    d: &'a str,
+
+    #[getter(ref)]
+    e: Vec<u8>,
+
+    #[getter(clone)]
+    f: String,
+
+    #[getter(skip)]
+    g: String,
+}
+
+#[derive(Getters)]
+#[getters(clone)]
+struct AllClone {
+    a: String,
+    b: Rc<u64>,
+
+    #[getter(copy, rename = "number")]
+    c: u64,
+}
+
+fn main() {
+    let point = Point { x: 3, y: -4, z: 5 };
+    let sum: i64 = point.x() + point.y();
+    assert!(sum == -1);
+    assert!(*point.z() == 5);
+
+    let shared = Shared { name: Rc::new("Shared".to_owned()), tag: "Tag".to_owned() };
+    let name: Rc<String> = shared.name();
+    assert!(Rc::strong_count(&name) == 2);
+    let label: String = shared.label();
+    assert!(label == "Tag");
+
+    let all = AllCopy {
+        a: 1,
+        b: 2.5,
+        c: 'c',
+        d: "Hello",
+        e: vec![1, 2, 3],
+        f: "Owned".to_owned(),
+        g: "Hidden".to_owned(),
+    };
+    assert!(all.a() == 1u8);
+    assert!(all.b() == 2.5f64);
+    assert!(all.c() == 'c');
+    assert!(all.d() == "Hello");
+    assert!(all.e() == &vec![1, 2, 3]);
+    assert!(all.f() == "Owned");
+    assert!(all.g == "Hidden");
+
+    let all = AllClone { a: "A".to_owned(), b: Rc::new(2), c: 3 };
+    let a: String = all.a();
+    let b: Rc<u64> = all.b();
+    let c: u64 = all.number();
+    assert!(a == "A");
+    assert!(*b == 2);
+    assert!(c == 3);
+}
@@ -0,0 +1,102 @@
+//! Getters returning the borrowed form of common owned types.
+
+use std::borrow::Cow;
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+use derive_getters::Getters;
+
+#[derive(Debug, PartialEq, Eq)]
+struct Inner(u64);
+
+#[derive(Getters)]
+struct Selective {
+    #[getter(deref)]
+    name: String,
+
+    #[getter(deref)]
+    bytes: Vec<u8>,
+
+    #[getter(deref)]
+    boxed: Box<Inner>,
+
+    #[getter(deref)]
+    path: PathBuf,
+
+    #[getter(deref)]
+    os: OsString,
+
+    #[getter(deref, rename = "cow")]
+    text: Cow<'static, str>,
+
+    untouched: String,
+}
+
+#[derive(Getters)]
+#[getters(deref)]
+struct Everything<'a, T> {
+    name: std::string::String,
+    items: Vec<T>,
+    maybe_name: Option<String>,
+    maybe_items: Option<Vec<T>>,
+    maybe_inner: Option<Inner>,
+    unknown: u64,
+    reference: &'a str,
+
+    #[getter(ref)]
+    full: String,
+}
+
+fn main() {
+    let s = Selective {
+        name: "Name".to_owned(),
+        bytes: vec![1, 2, 3],
+        boxed: Box::new(Inner(9)),
+        path: PathBuf::from("/tmp"),
+        os: OsString::from("os"),
+        text: Cow::Borrowed("cow"),
+        untouched: "Untouched".to_owned(),
+    };
+
+    let name: &str = s.name();
+    let bytes: &[u8] = s.bytes();
+    let boxed: &Inner = s.boxed();
+    let path: &Path = s.path();
+    let os: &OsStr = s.os();
+    let cow: &str = s.cow();
+    let untouched: &String = s.untouched();
+    assert!(name == "Name");
+    assert!(bytes == [1, 2, 3]);
+    assert!(*boxed == Inner(9));
+    assert!(path == Path::new("/tmp"));
+    assert!(os == "os");
+    assert!(cow == "cow");
+    assert!(untouched == "Untouched");
+
+    let e = Everything {
+        name: "Name".to_owned(),
+        items: vec!['a', 'b'],
+        maybe_name: Some("Maybe".to_owned()),
+        maybe_items: None,
+        maybe_inner: Some(Inner(3)),
+        unknown: 4,
+        reference: "Ref",
+        full: "Full".to_owned(),
+    };
+
+    let name: &str = e.name();
+    let items: &[char] = e.items();
+    let maybe_name: Option<&str> = e.maybe_name();
+    let maybe_items: Option<&[char]> = e.maybe_items();
+    let maybe_inner: Option<&Inner> = e.maybe_inner();
+    let unknown: &u64 = e.unknown();
+    let reference: &str = e.reference();
+    let full: &String = e.full();
+    assert!(name == "Name");
+    assert!(items == ['a', 'b']);
+    assert!(maybe_name == Some("Maybe"));
+    assert!(maybe_items.is_none());
+    assert!(maybe_inner == Some(&Inner(3)));
+    assert!(*unknown == 4);
+    assert!(reference == "Ref");
+    assert!(full == "Full");
+}
@@ -0,0 +1,77 @@
+//! Visibility of generated methods.
+
+use derive_getters::Getters;
+
+mod shapes {
+    use derive_getters::{Getters, Dissolve, DissolveRef, DissolveMut};
+
+    pub(crate) struct Hidden(pub(crate) u64);
+
+    #[derive(Getters, Dissolve, DissolveRef, DissolveMut)]
+    #[getters(vis = "pub(crate)")]
+    #[dissolve(vis = "pub(crate)")]
+    #[dissolve_ref(vis = "pub(crate)", rename = "parts")]
+    #[dissolve_mut(vis = "pub(crate)")]
+    pub struct Crated {
+        hidden: Hidden,
+
+        #[getter(vis = "pub")]
+        public: u64,
+    }
+
+    impl Crated {
+        pub fn new(hidden: u64, public: u64) -> Self {
+            Crated { hidden: Hidden(hidden), public }
+        }
+    }
+
+    #[derive(Getters, Dissolve)]
+    #[getters(vis = "inherit")]
+    #[dissolve(vis = "inherit")]
+    pub(super) struct Inherited {
+        pub(super) open: u64,
+        closed: u64,
+
+        #[getter(vis = "pub(super)", rename = "unlocked")]
+        locked: u64,
+    }
+
+    impl Inherited {
+        pub(super) fn new(open: u64, closed: u64, locked: u64) -> Self {
+            Inherited { open, closed, locked }
+        }
+
+        pub(super) fn closed_plus_one(&self) -> u64 {
+            *self.closed() + 1
+        }
+    }
+}
+
+#[derive(Getters)]
+struct Private {
+    #[getter(vis = "")]
+    field: u64,
+}
+
+fn main() {
+    let mut crated = shapes::Crated::new(1, 2);
+    assert!(crated.hidden().0 == 1);
+    assert!(*crated.public() == 2);
+    let (hidden, _) = crated.dissolve_mut();
+    hidden.0 = 3;
+    let (hidden, _) = crated.parts();
+    assert!(hidden.0 == 3);
+    let (hidden, public) = crated.dissolve();
+    assert!(hidden.0 == 3);
+    assert!(public == 2);
+
+    let inherited = shapes::Inherited::new(1, 2, 3);
+    assert!(*inherited.open() == 1);
+    assert!(*inherited.unlocked() == 3);
+    assert!(inherited.closed_plus_one() == 3);
+    let (open, closed, locked) = inherited.dissolve();
+    assert!(open + closed + locked == 6);
+
+    let private = Private { field: 4 };
+    assert!(*private.field() == 4);
+}
@@ -0,0 +1,95 @@
+//! Struct wide naming conventions for getters.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+#[getters(prefix = "get_")]
+struct Prefixed {
+    name: String,
+
+    #[getter(rename = "count")]
+    number: u64,
+
+    r#type: u8,
+}
+
+#[derive(Getters)]
+#[getters(suffix = "_ref")]
+struct Suffixed {
+    name: String,
+}
+
+#[derive(Getters)]
+#[getters(prefix = "get_", rename_all = "camelCase")]
+struct Camel {
+    first_name: String,
+    last_name: String,
+
+    #[getter(rename = "years")]
+    age: u8,
+}
+
+#[derive(Getters)]
+#[getters(rename_all = "camelCase")]
+struct Keyword {
+    r#type: u8,
+    r#match_all: bool,
+}
+
+#[derive(Getters)]
+#[getters(prefix = "sel")]
+struct Selected {
+    #[getter(skip)]
+    f: u8,
+
+    ected: u8,
+}
+
+#[derive(Getters)]
+#[getters(prefix = "sel")]
+struct Renamed {
+    #[getter(rename = "value")]
+    f: u8,
+}
+
+#[derive(Getters)]
+#[getters(rename_all = "PascalCase", copy)]
+struct Pascal {
+    first_value: u64,
+}
+
+#[derive(Getters)]
+#[getters(prefix = "fetch_", suffix = "_now")]
+#[getters(rename_all = "snake_case")]
+struct Both {
+    value: u64,
+}
+
+fn main() {
+    let p = Prefixed { name: "Name".to_owned(), number: 4, r#type: 2 };
+    assert!(p.get_name() == "Name");
+    assert!(*p.count() == 4);
+    assert!(*p.get_type() == 2);
+
+    let s = Suffixed { name: "Name".to_owned() };
+    assert!(s.name_ref() == "Name");
+
+    let c = Camel { first_name: "First".to_owned(), last_name: "Last".to_owned(), age: 3 };
+    assert!(c.getFirstName() == "First");
+    assert!(c.getLastName() == "Last");
+    assert!(*c.years() == 3);
+
+    let k = Keyword { r#type: 7, r#match_all: true };
+    assert!(*k.r#type() == 7);
+    assert!(*k.matchAll());
+
+    let s = Selected { f: 1, ected: 3 };
+    assert!(s.f == 1 && *s.selected() == 3);
+    assert!(*Renamed { f: 2 }.value() == 2);
+
+    let p = Pascal { first_value: 5 };
+    assert!(p.FirstValue() == 5);
+
+    let b = Both { value: 6 };
+    assert!(*b.fetch_value_now() == 6);
+}
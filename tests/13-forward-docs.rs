@@ -0,0 +1,44 @@
+//! Documentation on fields is carried over to the getters and the dissolve methods are
+//! documented too, so `missing_docs` is satisfied.
+#![deny(missing_docs)]
+
+use derive_getters::{Getters, Dissolve, DissolveRef, DissolveMut};
+
+/// A person.
+#[derive(Getters, Dissolve, DissolveRef, DissolveMut)]
+pub struct Person {
+    /// The name of the person.
+    name: String,
+
+    /// The age of the person
+    /// in years.
+    #[getter(rename = "years")]
+    age: u8,
+}
+
+/// A point with a documentation template for its getters.
+#[derive(Getters)]
+#[getters(doc = "Returns the `{field}` coordinate.", copy)]
+pub struct Point {
+    x: i64,
+
+    /// The height above sea level.
+    y: i64,
+
+    r#z: i64,
+}
+
+fn main() {
+    let mut person = Person { name: "Alice".to_owned(), age: 30 };
+    assert!(person.name() == "Alice");
+    assert!(*person.years() == 30);
+    let (_, age) = person.dissolve_mut();
+    *age += 1;
+    let (_, age) = person.dissolve_ref();
+    assert!(*age == 31);
+    let (name, _) = person.dissolve();
+    assert!(name == "Alice");
+
+    let point = Point { x: 1, y: 2, z: 3 };
+    assert!(point.x() + point.y() + point.z() == 6);
+}
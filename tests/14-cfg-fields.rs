@@ -0,0 +1,49 @@
+//! Fields behind `cfg` attributes.
+
+use derive_getters::{Getters, Dissolve, DissolveRef, DissolveMut};
+
+#[derive(Getters, Dissolve, DissolveRef, DissolveMut)]
+struct Gated {
+    always: u64,
+
+    #[cfg(any())]
+    never: String,
+
+    #[cfg(all())]
+    #[getter(rename = "present")]
+    enabled: u64,
+
+    #[cfg_attr(all(), getter(copy))]
+    #[cfg_attr(any(), getter(skip))]
+    conditional: u64,
+}
+
+impl Gated {
+    #[cfg(all())]
+    fn new(always: u64, enabled: u64, conditional: u64) -> Self {
+        Gated { always, enabled, conditional }
+    }
+
+    // Would collide with the getter if the getter were not gated out with its field.
+    #[allow(dead_code)]
+    fn never(&self) -> u64 {
+        0
+    }
+}
+
+fn main() {
+    let mut gated = Gated::new(1, 2, 3);
+    assert!(*gated.always() == 1);
+    assert!(*gated.present() == 2);
+    assert!(gated.conditional() == 3);
+    assert!(gated.never() == 0);
+
+    let (always, enabled, conditional) = gated.dissolve_mut();
+    *always += 1;
+    *enabled += 1;
+    *conditional += 1;
+    let (always, enabled, conditional) = gated.dissolve_ref();
+    assert!(*always + *enabled + *conditional == 9);
+    let (always, enabled, conditional) = gated.dissolve();
+    assert!(always + enabled + conditional == 9);
+}
@@ -0,0 +1,52 @@
+//! Attributes and `const` on generated getters.
+#![deny(unused_must_use)]
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+#[getters(const, inline)]
+struct Point {
+    #[getter(copy)]
+    x: i64,
+
+    y: i64,
+
+    #[getter(deref)]
+    name: Option<u8>,
+
+    // Can't be const so the struct wide option passes over it.
+    #[getter(clone)]
+    label: Option<String>,
+}
+
+const ORIGIN: Point = Point { x: 0, y: 0, name: None, label: None };
+const ORIGIN_X: i64 = ORIGIN.x();
+const ORIGIN_Y: &i64 = ORIGIN.y();
+const ORIGIN_NAME: Option<&u8> = ORIGIN.name();
+
+#[derive(Getters)]
+#[getters(must_use)]
+struct Checked {
+    #[getter(const, copy)]
+    value: u64,
+
+    #[getter(attr(deprecated(note = "use `value` instead")))]
+    old_value: u64,
+
+    #[getter(attr(doc(alias = "everything")), inline)]
+    answer: u64,
+}
+
+fn main() {
+    assert!(ORIGIN_X == 0);
+    assert!(*ORIGIN_Y == 0);
+    assert!(ORIGIN_NAME.is_none());
+    assert!(ORIGIN.label().is_none());
+
+    let checked = Checked { value: 1, old_value: 2, answer: 42 };
+    assert!(checked.value() == 1);
+    #[allow(deprecated)]
+    let old = *checked.old_value();
+    assert!(old == 2);
+    assert!(*checked.answer() == 42);
+}
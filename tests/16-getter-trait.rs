@@ -0,0 +1,66 @@
+//! Getters declared in, and implemented through, a trait.
+
+use derive_getters::Getters;
+
+mod people {
+    use derive_getters::Getters;
+
+    /// Someone with a name and an age.
+    #[derive(Getters)]
+    #[getters(trait = "PersonFields")]
+    pub struct Person {
+        /// Name of the person.
+        name: String,
+        age: u8,
+    }
+
+    impl Person {
+        pub fn new(name: &str, age: u8) -> Self {
+            Person { name: name.to_owned(), age }
+        }
+    }
+}
+
+use people::PersonFields;
+
+#[derive(Getters)]
+#[getters(impl_trait = "people::PersonFields", trait_only)]
+struct Robot {
+    name: String,
+    age: u8,
+}
+
+#[derive(Getters)]
+#[getters(trait = "Labelled", copy, inline, must_use)]
+struct Label<'a, T: Copy, const N: usize>
+where
+    T: Default,
+{
+    text: &'a str,
+    value: T,
+
+    #[getter(ref)]
+    buffer: [T; N],
+}
+
+fn audit(fields: &impl PersonFields) -> String {
+    format!("{} is {}", fields.name(), fields.age())
+}
+
+fn label_sum<'a, L: Labelled<'a, u8, 2>>(label: &L) -> u8 {
+    label.value() + label.buffer().iter().sum::<u8>()
+}
+
+fn main() {
+    let person = people::Person::new("Alice", 30);
+    assert!(audit(&person) == "Alice is 30");
+
+    let robot = Robot { name: "Robbie".to_owned(), age: 2 };
+    assert!(audit(&robot) == "Robbie is 2");
+    assert!(PersonFields::name(&robot) == "Robbie");
+
+    let label = Label { text: "Hi", value: 1u8, buffer: [2, 3] };
+    assert!(label_sum(&label) == 6);
+    assert!(label.text() == "Hi");
+    assert!(Labelled::text(&label) == "Hi");
+}
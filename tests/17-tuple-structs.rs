@@ -0,0 +1,100 @@
+//! Tuple structs, newtypes and unit structs.
+
+use derive_getters::{Getters, Dissolve, DissolveRef, DissolveMut};
+
+#[derive(Getters, Dissolve, DissolveRef, DissolveMut)]
+#[getters(into_inner)]
+#[dissolve(unwrap_single)]
+#[dissolve_ref(unwrap_single)]
+#[dissolve_mut(unwrap_single)]
+struct UserId(u64);
+
+#[derive(Getters, Dissolve, DissolveRef, DissolveMut)]
+struct Pair<T>(String, T);
+
+#[derive(Getters)]
+struct Named(
+    #[getter(rename = "id")]
+    u64,
+
+    #[getter(skip)]
+    String,
+
+    #[getter(copy)]
+    u8,
+);
+
+#[derive(Getters)]
+#[getters(prefix = "get_")]
+struct Prefixed(u64, u64);
+
+#[derive(Getters)]
+#[getters(into_inner)]
+struct Wrapper<'a>(#[getter(rename = "text", deref)] &'a String);
+
+#[derive(Getters, Dissolve, DissolveRef, DissolveMut)]
+struct Unit;
+
+#[derive(Getters)]
+struct Handle(String);
+
+impl Drop for Handle {
+    fn drop(&mut self) {}
+}
+
+#[derive(Getters)]
+struct Counter(u32);
+
+impl Counter {
+    fn into_inner(self) -> u64 {
+        u64::from(self.0)
+    }
+}
+
+fn main() {
+    let mut id = UserId(7);
+    assert!(*id.inner() == 7);
+    let inner = id.dissolve_mut();
+    *inner += 1;
+    let inner = id.dissolve_ref();
+    assert!(*inner == 8);
+    assert!(id.into_inner() == 8);
+    assert!(UserId(9).dissolve() == 9);
+
+    let mut pair = Pair("Left".to_owned(), 'r');
+    assert!(pair._0() == "Left");
+    assert!(*pair._1() == 'r');
+    let (_, right) = pair.dissolve_mut();
+    *right = 'R';
+    let (left, right) = pair.dissolve_ref();
+    assert!(left == "Left");
+    assert!(*right == 'R');
+    let (left, right) = pair.dissolve();
+    assert!(left == "Left");
+    assert!(right == 'R');
+
+    let named = Named(1, "Hidden".to_owned(), 2);
+    assert!(*named.id() == 1);
+    assert!(named._2() == 2);
+    assert!(named.1 == "Hidden");
+
+    let prefixed = Prefixed(3, 4);
+    assert!(*prefixed.get_0() + *prefixed.get_1() == 7);
+
+    let owned = "Text".to_owned();
+    let wrapper = Wrapper(&owned);
+    assert!(wrapper.text() == "Text");
+    assert!(wrapper.into_inner() == "Text");
+
+    let handle = Handle("Handle".to_owned());
+    assert!(handle.inner() == "Handle");
+
+    let counter = Counter(10);
+    assert!(*counter.inner() == 10);
+    assert!(counter.into_inner() == 10u64);
+
+    let mut unit = Unit;
+    let () = unit.dissolve_mut();
+    let () = unit.dissolve_ref();
+    let () = unit.dissolve();
+}
@@ -0,0 +1,123 @@
+//! Getters for the fields shared by the variants of an enum.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+enum Event {
+    Created {
+        /// Identifier of the event.
+        id: u64,
+        #[getter(copy)]
+        timestamp: i64,
+        name: String,
+    },
+    Deleted {
+        id: u64,
+        timestamp: i64,
+    },
+    Renamed {
+        timestamp: i64,
+        #[getter(rename = "ident")]
+        id: u64,
+        from: String,
+        to: String,
+    },
+}
+
+#[derive(Getters)]
+#[getters(partial)]
+enum Shape<'a, T> {
+    Circle { centre: (T, T), radius: T, label: &'a str },
+    Square { centre: (T, T), side: T, label: &'a str },
+    Point(T),
+    Empty,
+}
+
+#[derive(Getters)]
+#[getters(partial, deref, prefix = "get_")]
+enum Message {
+    Text { body: String },
+    Bytes { body: String, length: usize },
+    Ping(u8),
+    Pong(u8),
+}
+
+#[derive(Getters)]
+enum Pair {
+    Left(u64, String),
+    Right(u64, String),
+}
+
+#[derive(Getters)]
+enum Tup {
+    A(u8),
+    B(String),
+}
+
+#[derive(Getters)]
+#[getters(partial)]
+enum Record {
+    A { id: u64, value: i32 },
+    B { id: u64, value: String },
+}
+
+#[allow(dead_code)]
+trait Value {
+    fn value(&self) -> &str {
+        "Value"
+    }
+}
+
+impl Value for Record {}
+
+#[derive(Getters)]
+enum Nothing {}
+
+fn main() {
+    let created = Event::Created { id: 1, timestamp: 10, name: "Name".to_owned() };
+    let deleted = Event::Deleted { id: 2, timestamp: 20 };
+    let renamed = Event::Renamed {
+        timestamp: 30,
+        id: 3,
+        from: "From".to_owned(),
+        to: "To".to_owned(),
+    };
+    assert!(*created.ident() == 1);
+    assert!(*deleted.ident() == 2);
+    assert!(*renamed.ident() == 3);
+    let timestamp: i64 = renamed.timestamp();
+    assert!(timestamp + created.timestamp() + deleted.timestamp() == 60);
+
+    let circle = Shape::Circle { centre: (0, 0), radius: 3, label: "Circle" };
+    let point = Shape::Point(4);
+    let empty: Shape<'_, i32> = Shape::Empty;
+    assert!(circle.centre() == Some(&(0, 0)));
+    assert!(circle.radius() == Some(&3));
+    assert!(circle.side().is_none());
+    assert!(circle.label() == Some("Circle"));
+    assert!(point._0() == Some(&4));
+    assert!(point.centre().is_none());
+    assert!(empty._0().is_none());
+
+    let text = Message::Text { body: "Body".to_owned() };
+    let ping = Message::Ping(1);
+    let body: Option<&str> = text.get_body();
+    assert!(body == Some("Body"));
+    assert!(text.get_length().is_none());
+    assert!(ping.get_body().is_none());
+    assert!(ping.get_0() == Some(&1));
+
+    let left = Pair::Left(1, "Left".to_owned());
+    assert!(*left._0() == 1);
+    assert!(left._1() == "Left");
+
+    let _ = Tup::A(1);
+    let _ = Tup::B("B".to_owned());
+
+    let record = Record::B { id: 5, value: "B".to_owned() };
+    assert!(*record.id() == 5);
+    assert!(record.value() == "Value");
+    let _ = Record::A { id: 6, value: 6 };
+
+    let _ = std::mem::size_of::<Nothing>();
+}
@@ -0,0 +1,106 @@
+//! Accessors for the variants of an enum.
+
+use derive_getters::Variants;
+
+#[derive(Variants)]
+enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(String),
+    ChangeColour(u8, u8, u8),
+
+    #[variant(rename = "raw")]
+    RawBytes(Vec<u8>),
+
+    #[variant(skip)]
+    #[allow(dead_code)]
+    Ignored,
+}
+
+#[derive(Variants)]
+#[variant(vis = "inherit")]
+pub enum Either<'a, L, R: Clone> {
+    Left(&'a L),
+    Right(R),
+}
+
+#[derive(Variants)]
+#[allow(non_camel_case_types)]
+enum Keyword {
+    r#type(u8),
+    r#match,
+}
+
+#[derive(Variants)]
+enum Only {
+    Value { value: u64 },
+}
+
+mod private {
+    use derive_getters::Variants;
+
+    #[derive(Variants)]
+    pub enum Hidden {
+        #[variant(vis = "pub(crate)")]
+        Shown(u8),
+
+        #[variant(vis = "")]
+        #[allow(dead_code)]
+        Secret(u8),
+    }
+}
+
+fn main() {
+    let mut keyword = Keyword::r#type(1);
+    assert!(keyword.is_type() && !keyword.is_match());
+    if let Some(value) = keyword.as_type_mut() {
+        *value += 1;
+    }
+    assert!(keyword.as_type() == Some(&2));
+    assert!(keyword.into_type() == Some(2));
+    assert!(Keyword::r#match.is_match());
+
+    let quit = Message::Quit;
+    assert!(quit.is_quit());
+    assert!(!quit.is_move());
+    assert!(quit.as_write().is_none());
+
+    let mut moved = Message::Move { x: 1, y: -1 };
+    assert!(moved.is_move());
+    assert!(moved.as_move() == Some((&1, &-1)));
+    if let Some((x, y)) = moved.as_move_mut() {
+        *x += 10;
+        *y -= 10;
+    }
+    assert!(moved.into_move() == Some((11, -11)));
+
+    let write = Message::Write("hello".to_owned());
+    let text: Option<&String> = write.as_write();
+    assert!(text.map(String::as_str) == Some("hello"));
+    assert!(write.into_change_colour().is_none());
+
+    let colour = Message::ChangeColour(1, 2, 3);
+    assert!(colour.is_change_colour());
+    assert!(colour.into_change_colour() == Some((1, 2, 3)));
+
+    let mut raw = Message::RawBytes(vec![1, 2]);
+    assert!(raw.is_raw());
+    raw.as_raw_mut().unwrap().push(3);
+    assert!(raw.as_raw() == Some(&vec![1, 2, 3]));
+    assert!(raw.into_raw() == Some(vec![1, 2, 3]));
+
+    let number = 4u8;
+    let left: Either<u8, String> = Either::Left(&number);
+    assert!(left.is_left());
+    assert!(left.as_left() == Some(&&4));
+    assert!(left.into_right().is_none());
+
+    let mut only = Only::Value { value: 9 };
+    *only.as_value_mut().unwrap() += 1;
+    assert!(only.is_value());
+    assert!(only.into_value() == Some(10));
+
+    let shown = private::Hidden::Shown(5);
+    assert!(shown.is_shown());
+    assert!(shown.into_shown() == Some(5));
+}
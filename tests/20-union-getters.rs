@@ -0,0 +1,61 @@
+//! Unsafe getters for the fields of a union.
+#![deny(missing_docs)]
+
+use std::mem::ManuallyDrop;
+
+use derive_getters::Getters;
+
+/// A value that's either an integer or a float, going by some tag held elsewhere.
+#[derive(Getters)]
+#[getters(const)]
+#[repr(C)]
+pub union Number {
+    /// The value as an integer.
+    #[getter(copy)]
+    int: u32,
+    #[getter(copy, rename = "float")]
+    real: f32,
+}
+
+/// Bytes of a header, or its parsed form.
+#[derive(Getters)]
+#[getters(mut, vis = "pub(crate)")]
+pub union Header {
+    raw: [u8; 4],
+    parts: (u16, u16),
+}
+
+/// Union with a field needing drop, so it's wrapped in `ManuallyDrop`.
+#[derive(Getters)]
+#[getters(trait = "NameFields", mut)]
+pub union Name {
+    owned: ManuallyDrop<String>,
+    empty: (),
+}
+
+fn main() {
+    let number = Number { int: 1 };
+    assert!(unsafe { number.int() } == 1);
+
+    let number = Number { real: 1.5 };
+    assert!(unsafe { number.float() } == 1.5);
+
+    let mut header = Header { raw: [0, 1, 0, 2] };
+    assert!(unsafe { header.raw() } == &[0, 1, 0, 2]);
+    unsafe { header.raw_mut()[3] = 3 };
+    assert!(unsafe { *header.raw() } == [0, 1, 0, 3]);
+    *unsafe { header.parts_mut() } = (7, 7);
+    assert!(unsafe { header.raw() } == &[7, 0, 7, 0]);
+
+    let mut name = Name { owned: ManuallyDrop::new("union".to_owned()) };
+    assert!(unsafe { name.owned() }.as_str() == "union");
+    unsafe { NameFields::owned_mut(&mut name) }.push_str("s");
+    assert!(unsafe { NameFields::owned(&name) }.as_str() == "unions");
+    unsafe { ManuallyDrop::drop(name.owned_mut()) };
+
+    let name = Name { empty: () };
+    assert!(unsafe { name.empty() } == &());
+
+    const ONE: u32 = unsafe { Number { int: 1 }.int() };
+    assert!(ONE == 1);
+}
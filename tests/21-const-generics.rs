@@ -0,0 +1,92 @@
+//! Const generic parameters, with and without defaults, in every derive.
+
+use std::fmt::Debug;
+
+use derive_getters::{Getters, Dissolve, DissolveRef, DissolveMut, Variants};
+
+#[derive(Getters, Dissolve, DissolveRef, DissolveMut)]
+struct Buffer<T, const N: usize> {
+    #[getter(deref)]
+    data: [T; N],
+    len: usize,
+}
+
+#[derive(Getters, Dissolve, DissolveRef, DissolveMut)]
+#[getters(copy)]
+struct Defaulted<const N: usize = 4, const SIGNED: bool = false> {
+    count: usize,
+}
+
+#[derive(Getters, Dissolve, DissolveRef, DissolveMut)]
+#[getters(trait = "MixedFields")]
+struct Mixed<'a, T, const N: usize, U = u8>
+where
+    T: Debug + 'a,
+    U: Copy,
+    [T; N]: Debug,
+{
+    items: &'a [T; N],
+    #[getter(deref, const)]
+    owned: [U; N],
+    extra: Option<U>,
+}
+
+#[derive(Getters, Dissolve)]
+struct Wrapped<T: Default, const N: usize>([T; N]);
+
+#[derive(Getters)]
+#[getters(trait = "RingFields")]
+pub struct Ring<T, const N: usize = 2> {
+    data: [T; N],
+}
+
+#[derive(Getters, Variants)]
+#[getters(partial)]
+enum Packet<const N: usize> {
+    Small { header: u8, body: [u8; N] },
+    Large { header: u8, body: [u8; N], tail: Vec<u8> },
+}
+
+fn main() {
+    let mut buffer = Buffer { data: [1, 2, 3], len: 2 };
+    let data: &[i32] = buffer.data();
+    assert!(data == [1, 2, 3]);
+    assert!(*buffer.len() == 2);
+    assert!(buffer.dissolve_ref() == (&[1, 2, 3], &2));
+    buffer.dissolve_mut().0[0] = 10;
+    assert!(buffer.dissolve() == ([10, 2, 3], 2));
+
+    let defaulted: Defaulted = Defaulted { count: 3 };
+    assert!(defaulted.count() == 3);
+    let mut signed = Defaulted::<8, true> { count: 8 };
+    *signed.dissolve_mut().0 += 1;
+    assert!(signed.dissolve_ref() == (&9,));
+    assert!(signed.dissolve() == (9,));
+
+    let items = ["a", "b"];
+    let mut mixed: Mixed<&str, 2> = Mixed { items: &items, owned: [1, 2], extra: None };
+    assert!(mixed.items() == &["a", "b"]);
+    assert!(mixed.owned() == [1, 2]);
+    assert!(MixedFields::extra(&mixed).is_none());
+    *mixed.dissolve_mut().2 = Some(3);
+    assert!(mixed.dissolve_ref().2 == &Some(3));
+    let (dissolved, _, _) = mixed.dissolve();
+    assert!(dissolved == &["a", "b"]);
+
+    let wrapped = Wrapped([0u8; 5]);
+    assert!(wrapped.inner().len() == 5);
+    assert!(wrapped.dissolve() == ([0; 5],));
+
+    let ring: Ring<u8> = Ring { data: [4, 2] };
+    assert!(first(&ring) == Some(&4));
+
+    let packet = Packet::<2>::Small { header: 1, body: [7, 7] };
+    assert!(*packet.header() == 1);
+    assert!(packet.as_small() == Some((&1, &[7, 7])));
+    assert!(packet.tail().is_none());
+    assert!(!packet.is_large());
+}
+
+fn first<T, const N: usize>(ring: &impl RingFields<T, N>) -> Option<&T> {
+    ring.data().first()
+}
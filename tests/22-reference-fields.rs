@@ -0,0 +1,62 @@
+//! Getters of reference fields borrow `self` only for the call, handing back the
+//! reference with its own lifetime. Mutable references are reborrowed instead.
+
+use derive_getters::Getters;
+
+#[derive(Getters)]
+#[getters(trait = "ViewFields")]
+pub struct View<'a, T> {
+    text: &'a str,
+    items: &'a [T],
+}
+
+#[derive(Getters)]
+struct Cursor<'a> {
+    source: &'a str,
+    buffer: &'a mut Vec<u8>,
+    #[getter(rename = "position")]
+    pos: &'a mut usize,
+}
+
+#[derive(Getters)]
+enum Token<'a> {
+    Word { span: &'a str },
+    Number { span: &'a str, value: u64 },
+}
+
+/// The view is dropped before returning, which only works if the getter doesn't keep it
+/// borrowed for `'a`.
+fn first_word(text: &str) -> &str {
+    let view = View::<u8> { text, items: &[] };
+    view.text().split(' ').next().unwrap_or("")
+}
+
+fn token_span<'a>(token: &Token<'a>) -> &'a str {
+    token.span()
+}
+
+fn main() {
+    assert!(first_word("hello world") == "hello");
+
+    let numbers = vec![1, 2, 3];
+    let items = {
+        let view = View { text: "numbers", items: numbers.as_slice() };
+        ViewFields::items(&view)
+    };
+    assert!(items == [1, 2, 3]);
+
+    let mut buffer = vec![1u8];
+    let mut pos = 3;
+    let cursor = Cursor { source: "source", buffer: &mut buffer, pos: &mut pos };
+    let source = cursor.source();
+    let bytes: &Vec<u8> = cursor.buffer();
+    assert!(bytes == &[1]);
+    assert!(*cursor.position() == 3);
+    drop(cursor);
+    assert!(source == "source");
+
+    let input = String::from("42");
+    let span = token_span(&Token::Number { span: input.as_str(), value: 42 });
+    assert!(span == "42");
+    assert!(token_span(&Token::Word { span: "word" }) == "word");
+}
@@ -0,0 +1,81 @@
+//! Mutable getters, either for every field or only for those that opt in.
+#![deny(missing_docs)]
+
+use derive_getters::{Getters, MutGetters};
+
+/// Every field gets a mutable getter unless skipped.
+#[derive(Getters, MutGetters)]
+#[getters(doc = "The `{field}` setting.")]
+pub struct Settings {
+    volume: u8,
+
+    #[getter_mut(rename = "title_mut_ref", inline)]
+    title: String,
+
+    #[getter_mut(skip)]
+    id: u64,
+
+    #[getter_mut(vis = "pub(crate)", must_use)]
+    tags: Vec<String>,
+}
+
+/// Only the fields marked with `#[getter_mut]` get mutable getters.
+#[derive(Getters, MutGetters)]
+#[getters(doc = "The `{field}` of the account.")]
+#[getters_mut(opt_in)]
+pub struct Account {
+    id: u64,
+
+    #[getter_mut]
+    balance: i64,
+
+    #[getter_mut(rename = "history_mut")]
+    history: Vec<i64>,
+
+    #[getter_mut(vis = "inherit")]
+    pub(crate) note: Option<String>,
+}
+
+/// Tuple structs name their mutable getters by position, newtypes use `inner_mut`.
+#[derive(MutGetters)]
+#[getters_mut(vis = "pub(crate)")]
+pub struct Pair(u8, u16);
+
+/// A newtype.
+#[derive(MutGetters)]
+pub struct Meters<'a, T>(&'a mut T);
+
+fn main() {
+    let mut settings = Settings {
+        volume: 3,
+        title: "Default".to_owned(),
+        id: 1,
+        tags: Vec::new(),
+    };
+    *settings.volume_mut() += 1;
+    settings.title_mut_ref().push_str(" settings");
+    settings.tags_mut().push("new".to_owned());
+    assert!(*settings.volume() == 4);
+    assert!(settings.title() == "Default settings");
+    assert!(settings.tags().len() == 1);
+    assert!(*settings.id() == 1);
+
+    let mut account = Account { id: 9, balance: 0, history: Vec::new(), note: None };
+    *account.balance_mut() += 10;
+    account.history_mut().push(10);
+    *account.note_mut() = Some("first deposit".to_owned());
+    assert!(*account.balance() == 10);
+    assert!(account.history() == &[10]);
+    assert!(account.note().is_some());
+    assert!(*account.id() == 9);
+
+    let mut pair = Pair(1, 2);
+    *pair._0_mut() = 3;
+    *pair._1_mut() = 4;
+    assert!(pair.0 == 3 && pair.1 == 4);
+
+    let mut length = 5;
+    let mut meters = Meters(&mut length);
+    **meters.inner_mut() = 6;
+    assert!(length == 6);
+}
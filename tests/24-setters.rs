@@ -0,0 +1,127 @@
+//! Chainable setters, with conversion and validation.
+#![deny(missing_docs)]
+
+use std::fmt;
+
+use derive_getters::{Getters, Setters};
+
+/// Why a setting was refused.
+#[derive(Debug, PartialEq)]
+pub enum ConfigError {
+    /// The port was zero.
+    NoPort,
+    /// The name was empty.
+    NoName,
+}
+
+/// Error from a validator with its own error type, converted with `?`.
+#[derive(Debug)]
+pub struct Empty;
+
+impl From<Empty> for ConfigError {
+    fn from(_: Empty) -> Self {
+        ConfigError::NoName
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+mod checks {
+    use super::{ConfigError, Empty};
+
+    pub fn port(port: &u16) -> Result<(), ConfigError> {
+        if *port == 0 { Err(ConfigError::NoPort) } else { Ok(()) }
+    }
+
+    pub fn name(name: &String) -> Result<(), Empty> {
+        if name.is_empty() { Err(Empty) } else { Ok(()) }
+    }
+}
+
+/// Server configuration.
+#[derive(Getters, Setters)]
+#[getters(doc = "The `{field}` setting.")]
+#[setters(error = "ConfigError")]
+pub struct Config {
+    #[setter(into, validate = "checks::name")]
+    name: String,
+
+    #[setter(validate = "checks::port")]
+    port: u16,
+
+    #[setter(into)]
+    host: String,
+
+    verbose: bool,
+
+    #[setter(skip)]
+    id: u64,
+
+    #[setter(rename = "with_retries", vis = "pub(crate)")]
+    retries: u8,
+}
+
+/// Settings converted with `into` for every field.
+#[derive(Setters)]
+#[setters(into)]
+pub struct Labels<'a, T: Clone> {
+    title: String,
+    tags: Vec<T>,
+    borrowed: &'a str,
+}
+
+/// A newtype.
+#[derive(Setters)]
+pub struct Wrapper(u8);
+
+/// A tuple struct.
+#[derive(Setters)]
+pub struct Pair(u8, #[setter(validate = "checks::port", error = "ConfigError")] u16);
+
+fn main() {
+    let mut config = Config {
+        name: "server".to_owned(),
+        port: 80,
+        host: "localhost".to_owned(),
+        verbose: false,
+        id: 1,
+        retries: 0,
+    };
+
+    config
+        .set_host("example.com")
+        .set_verbose(true)
+        .with_retries(3);
+    assert!(config.host() == "example.com");
+    assert!(*config.verbose());
+    assert!(*config.retries() == 3);
+
+    assert!(config.set_port(0).is_err());
+    assert!(*config.port() == 80);
+    config.set_port(8080).unwrap().set_verbose(false);
+    assert!(*config.port() == 8080);
+
+    assert!(config.set_name("").err() == Some(ConfigError::NoName));
+    assert!(config.name() == "server");
+    config.set_name("renamed").unwrap();
+    assert!(config.name() == "renamed");
+    assert!(*config.id() == 1);
+
+    let text = String::from("borrowed");
+    let mut labels: Labels<u8> = Labels { title: String::new(), tags: Vec::new(), borrowed: "" };
+    labels.set_title("title").set_tags(vec![1, 2]).set_borrowed(text.as_str());
+    assert!(labels.title == "title" && labels.tags == [1, 2] && labels.borrowed == "borrowed");
+
+    let mut wrapper = Wrapper(1);
+    wrapper.set_inner(2);
+    assert!(wrapper.0 == 2);
+
+    let mut pair = Pair(1, 1);
+    pair.set_0(5).set_1(9).unwrap();
+    assert!(pair.0 == 5 && pair.1 == 9);
+    assert!(pair.set_1(0).err().map(|e| e.to_string()) == Some("NoPort".to_owned()));
+}
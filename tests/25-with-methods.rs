@@ -0,0 +1,73 @@
+//! `with_` methods for updating owned values.
+#![deny(missing_docs)]
+
+use std::path::PathBuf;
+
+use derive_getters::{Getters, With};
+
+/// Connection settings.
+#[derive(Getters, With, Clone, PartialEq, Debug)]
+#[getters(doc = "The `{field}` setting.")]
+pub struct Connection {
+    #[with(into)]
+    host: String,
+
+    port: u16,
+
+    #[with(skip)]
+    id: u64,
+
+    #[with(rename = "using_certificate", into, must_use)]
+    certificate: Option<PathBuf>,
+
+    #[with(vis = "pub(crate)", inline)]
+    retries: u8,
+}
+
+/// Generic value object.
+#[derive(With)]
+pub struct Labelled<'a, T: Clone> {
+    #[with(into)]
+    label: &'a str,
+    value: T,
+}
+
+/// Tuple structs number their `with_` methods, newtypes use `with_inner`.
+#[derive(With)]
+pub struct Pair(u8, #[with(into)] String);
+
+/// A newtype.
+#[derive(With)]
+pub struct Id(u64);
+
+fn main() {
+    let base = Connection {
+        host: "localhost".to_owned(),
+        port: 80,
+        id: 1,
+        certificate: None,
+        retries: 0,
+    };
+
+    let updated = base
+        .clone()
+        .with_port(8080)
+        .with_host("example.com")
+        .using_certificate(PathBuf::from("cert.pem"))
+        .with_retries(3);
+
+    assert!(updated.host() == "example.com");
+    assert!(*updated.port() == 8080);
+    assert!(*updated.id() == 1);
+    assert!(updated.certificate().as_deref() == Some(PathBuf::from("cert.pem").as_path()));
+    assert!(*updated.retries() == 3);
+    assert!(base.with_port(8080).with_host("example.com") != updated);
+
+    let labelled = Labelled { label: "one", value: 1 }.with_label("two").with_value(2);
+    assert!(labelled.label == "two" && labelled.value == 2);
+
+    let pair = Pair(1, String::new()).with_0(2).with_1("two");
+    assert!(pair.0 == 2 && pair.1 == "two");
+
+    assert!(Id(1).with_inner(2).0 == 2);
+}
@@ -0,0 +1,171 @@
+//! `New` constructors and the `From` tuple impls that undo `dissolve`.
+#![deny(missing_docs)]
+
+use std::marker::PhantomData;
+
+use derive_getters::{Getters, Dissolve, New};
+
+/// A stock item.
+#[derive(Getters, Dissolve, New, Debug, PartialEq)]
+#[getters(doc = "The `{field}` of the item.")]
+pub struct Item {
+    #[new(into)]
+    name: String,
+    price: f64,
+
+    #[new(default)]
+    tags: Vec<String>,
+
+    #[new(value = "1")]
+    count: usize,
+}
+
+/// Constructor renamed and restricted.
+#[derive(New)]
+#[new(rename = "with_parts", vis = "pub(crate)")]
+pub struct Parts<'a, T: Clone, const N: usize> {
+    label: &'a str,
+    values: [T; N],
+    #[new(into)]
+    note: Option<String>,
+}
+
+/// A tuple struct.
+#[derive(Dissolve, New, Debug, PartialEq)]
+pub struct Pair(u8, #[new(into)] String);
+
+/// A unit struct.
+#[derive(New)]
+pub struct Unit;
+
+/// A single field round trips through a one element tuple.
+#[derive(New)]
+pub struct Single {
+    value: u64,
+}
+
+/// Tagged with a marker that `dissolve` leaves out.
+#[derive(Dissolve, New)]
+pub struct Tagged<T> {
+    id: u64,
+    marker: PhantomData<T>,
+}
+
+/// Fields skipped and moved by `dissolve`.
+#[derive(Dissolve, New, Debug, PartialEq)]
+pub struct Entry {
+    key: String,
+    #[dissolve(position = 0)]
+    rank: u8,
+    #[dissolve(skip)]
+    #[new(default)]
+    cache: Option<String>,
+    #[dissolve(skip)]
+    #[new(value = "3")]
+    retries: u32,
+}
+
+/// A handle without a default value.
+pub struct NoDefault(u8);
+
+/// A field `dissolve` skips with no value for `From` to give it.
+#[derive(Dissolve, New)]
+pub struct Connection {
+    address: String,
+    #[dissolve(skip)]
+    handle: NoDefault,
+}
+
+/// Returned as its one field.
+#[derive(Dissolve, New)]
+#[dissolve(unwrap_single)]
+pub struct Meters(u8);
+
+/// Returned in chunks.
+#[derive(Dissolve, New, Debug, PartialEq)]
+#[dissolve(chunk = 2)]
+pub struct Rgb {
+    red: u8,
+    green: u8,
+    blue: u8,
+}
+
+/// Returned as a parts struct.
+#[derive(Dissolve, New, Debug, PartialEq)]
+#[dissolve(parts = "AccountParts")]
+pub struct Account<T> {
+    owner: String,
+    #[dissolve(skip)]
+    #[new(default)]
+    cache: Option<T>,
+    #[cfg(test)]
+    note: String,
+}
+
+/// A tuple struct returned as a parts struct.
+#[derive(Dissolve, New, Debug, PartialEq)]
+#[dissolve(parts = "RangeParts")]
+pub struct Range(#[dissolve(position = 1)] u8, u8);
+
+fn main() {
+    let item = Item::new("Hogie", 12.5);
+    assert!(item.name() == "Hogie");
+    assert!(*item.price() == 12.5);
+    assert!(item.tags().is_empty());
+    assert!(*item.count() == 1);
+
+    let item = Item::from(("Hogie".to_owned(), 1.0, vec!["food".to_owned()], 3));
+    let (name, price, tags, count) = item.dissolve();
+    assert!(name == "Hogie" && price == 1.0 && tags == ["food"] && count == 3);
+
+    let round: Item = Item::from(Item::new("Loop", 2.0).dissolve());
+    assert!(round == Item::new("Loop", 2.0));
+
+    let parts = Parts::with_parts("label", [1, 2, 3], "note".to_owned());
+    assert!(parts.label == "label" && parts.values == [1, 2, 3]);
+    assert!(parts.note.as_deref() == Some("note"));
+    let parts: Parts<u8, 0> = Parts::from(("empty", [], None));
+    assert!(parts.note.is_none());
+
+    let pair = Pair::new(1, "one");
+    assert!(Pair::from(pair.dissolve()) == Pair(1, "one".to_owned()));
+
+    let _unit: Unit = Unit::new();
+    let _unit: Unit = Unit::from(());
+
+    assert!(Single::new(4).value == 4);
+    assert!(Single::from((5,)).value == 5);
+
+    let tagged: Tagged<String> = Tagged::new(6, PhantomData);
+    let tagged: Tagged<String> = Tagged::from(tagged.dissolve());
+    assert!(tagged.id == 6);
+    let _marker: PhantomData<String> = tagged.marker;
+
+    let entry = Entry::new(2, "key".to_owned());
+    assert!(entry.cache.is_none());
+    let (rank, key) = entry.dissolve();
+    assert!(rank == 2 && key == "key");
+    let entry = Entry::from((rank, key));
+    assert!(entry == Entry { key: "key".to_owned(), rank: 2, cache: None, retries: 3 });
+
+    let connection = Connection::new("address".to_owned(), NoDefault(1));
+    assert!(connection.handle.0 == 1);
+    let (address,) = connection.dissolve();
+    assert!(address == "address");
+
+    let meters = Meters::from(Meters::new(4).dissolve());
+    assert!(meters.0 == 4);
+
+    let rgb = Rgb::new(1, 2, 3);
+    assert!(rgb.dissolve() == ((1, 2), (3,)));
+    assert!(Rgb::from(((1, 2), (3,))) == Rgb::new(1, 2, 3));
+
+    let account: Account<u8> = Account::new("owner".to_owned());
+    let account: Account<u8> = Account::from(account.dissolve());
+    assert!(account.owner == "owner" && account.cache.is_none());
+
+    let range = Range::from(RangeParts(2, 1));
+    assert!(range == Range(1, 2));
+    assert!(Range::from(range.dissolve()) == Range(1, 2));
+    assert!(Range::new(2, 1) == Range(1, 2));
+}
@@ -0,0 +1,57 @@
+//! `From` impls turning structs, or references to them, into tuples of their fields.
+
+use derive_getters::{Dissolve, DissolveRef, DissolveMut};
+
+#[derive(Dissolve, DissolveRef, DissolveMut, Clone)]
+#[dissolve(impl_from)]
+#[dissolve_ref(impl_from)]
+#[dissolve_mut(impl_from)]
+struct Point {
+    x: i64,
+    y: i64,
+}
+
+#[derive(Dissolve, DissolveRef, DissolveMut)]
+#[dissolve(impl_from, rename = "split")]
+#[dissolve_ref(impl_from, rename = "split_ref")]
+#[dissolve_mut(impl_from, rename = "split_mut")]
+struct Labelled<'a, T: Clone, const N: usize> {
+    label: &'a str,
+    values: [T; N],
+}
+
+#[derive(Dissolve)]
+#[dissolve(impl_from)]
+struct Pair(u8, String);
+
+fn sum(point: impl Into<(i64, i64)>) -> i64 {
+    let (x, y) = point.into();
+    x + y
+}
+
+fn main() {
+    let point = Point { x: 1, y: 2 };
+    assert!(sum(point.clone()) == 3);
+
+    let points = vec![point.clone(), Point { x: 3, y: 4 }];
+    let tuples: Vec<(i64, i64)> = points.into_iter().map(Into::into).collect();
+    assert!(tuples == [(1, 2), (3, 4)]);
+
+    let mut point = point;
+    let (x, y): (&i64, &i64) = (&point).into();
+    assert!(*x == 1 && *y == 2);
+    let (x, _): (&mut i64, &mut i64) = (&mut point).into();
+    *x = 10;
+    assert!(<(i64, i64)>::from(point) == (10, 2));
+
+    let mut labelled = Labelled { label: "label", values: [1u8, 2] };
+    let (_, values) = <(&mut &str, &mut [u8; 2])>::from(&mut labelled);
+    values[0] = 5;
+    let (label, values): (&&str, &[u8; 2]) = From::from(&labelled);
+    assert!(*label == "label" && *values == [5, 2]);
+    let (label, values): (&str, [u8; 2]) = labelled.into();
+    assert!(label == "label" && values == [5, 2]);
+
+    let (number, text): (u8, String) = Pair(1, "one".to_owned()).into();
+    assert!(number == 1 && text == "one");
+}
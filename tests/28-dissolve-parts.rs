@@ -0,0 +1,121 @@
+//! Dissolving into generated structs of fields rather than tuples.
+#![deny(missing_docs)]
+
+use std::marker::PhantomData;
+
+use derive_getters::{Dissolve, DissolveRef, DissolveMut};
+
+/// An order.
+#[derive(Dissolve, DissolveRef, DissolveMut)]
+#[dissolve(parts = "OrderParts", impl_from)]
+#[dissolve_ref(parts = "OrderPartsRef", impl_from)]
+#[dissolve_mut(parts = "OrderPartsMut")]
+pub struct Order {
+    id: u64,
+    total: f64,
+    lines: Vec<String>,
+    #[cfg(test)]
+    note: String,
+}
+
+/// Generic over lifetimes, types and consts.
+#[derive(Dissolve, DissolveRef, DissolveMut)]
+#[dissolve(parts = "LabelledParts", rename = "split")]
+#[dissolve_ref(parts = "LabelledRef")]
+#[dissolve_mut(parts = "LabelledMut", impl_from)]
+pub struct Labelled<'a, T: Clone, const N: usize> where T: Default {
+    label: &'a str,
+    values: [T; N],
+}
+
+/// A tuple struct.
+#[derive(Dissolve, DissolveRef)]
+#[dissolve(parts = "PairParts")]
+#[dissolve_ref(parts = "PairRef")]
+pub struct Pair(u8, String);
+
+/// Tagged with a type that no dissolved field holds.
+#[derive(Dissolve, DissolveRef)]
+#[dissolve(parts = "TaggedParts")]
+#[dissolve_ref(parts = "TaggedRef")]
+pub struct Tagged<T> {
+    id: u64,
+    _marker: PhantomData<T>,
+}
+
+/// No fields at all.
+#[derive(Dissolve, DissolveRef, DissolveMut)]
+#[dissolve(parts = "UnitParts")]
+#[dissolve_ref(parts = "UnitRef")]
+#[dissolve_mut(parts = "UnitMut")]
+pub struct Unit;
+
+/// The only field holding `T` is skipped.
+#[derive(Dissolve)]
+#[dissolve(parts = "CachedParts")]
+pub struct Cached<'a, T> {
+    name: &'a str,
+    #[dissolve(skip)]
+    cache: Option<T>,
+}
+
+/// A tuple struct whose only field holding `T` is skipped.
+#[derive(DissolveMut)]
+#[dissolve_mut(parts = "SlotMut")]
+pub struct Slot<T>(u8, #[dissolve_mut(skip)] Option<T>);
+
+fn main() {
+    let mut order = Order { id: 7, total: 9.5, lines: vec!["pen".to_owned()] };
+
+    let OrderPartsMut { total, lines, .. } = order.dissolve_mut();
+    *total += 0.5;
+    lines.push("ink".to_owned());
+
+    let OrderPartsRef { id, total, .. } = order.dissolve_ref();
+    assert!(*id == 7 && *total == 10.0);
+    let parts: OrderPartsRef = (&order).into();
+    assert!(parts.lines.len() == 2);
+
+    let OrderParts { id, lines, .. } = order.dissolve();
+    assert!(id == 7 && lines == ["pen", "ink"]);
+
+    let order = Order { id: 8, total: 1.0, lines: Vec::new() };
+    let parts: OrderParts = order.into();
+    assert!(parts.id == 8);
+
+    let mut labelled = Labelled { label: "label", values: [1u8, 2] };
+    let LabelledMut { values, .. } = (&mut labelled).into();
+    values[0] = 5;
+    let LabelledRef { label, values } = labelled.dissolve_ref();
+    assert!(**label == *"label" && *values == [5, 2]);
+    let LabelledParts { label, values } = labelled.split();
+    assert!(label == "label" && values == [5, 2]);
+
+    let pair = Pair(1, "one".to_owned());
+    let PairRef(number, _) = pair.dissolve_ref();
+    assert!(*number == 1);
+    let PairParts(number, text) = pair.dissolve();
+    assert!(number == 1 && text == "one");
+
+    let tagged: Tagged<String> = Tagged { id: 3, _marker: PhantomData };
+    let TaggedRef { id, .. } = tagged.dissolve_ref();
+    assert!(*id == 3);
+    let TaggedParts { id, .. } = tagged.dissolve();
+    assert!(id == 3);
+
+    let mut unit = Unit;
+    let UnitMut { .. } = unit.dissolve_mut();
+    let UnitRef { .. } = unit.dissolve_ref();
+    let UnitParts = unit.dissolve();
+
+    let cached = Cached { name: "cached", cache: Some(1u8) };
+    assert!(cached.cache.is_some());
+    let CachedParts { name, .. } = cached.dissolve();
+    assert!(name == "cached");
+
+    let mut slot = Slot(4, Some('a'));
+    assert!(slot.1.is_some());
+    let SlotMut(number, _) = slot.dissolve_mut();
+    *number += 1;
+    assert!(slot.0 == 5);
+}
@@ -0,0 +1,64 @@
+//! Skipping fields and ordering the tuple of the dissolve methods.
+
+use std::marker::PhantomData;
+
+use derive_getters::{Dissolve, DissolveRef, DissolveMut};
+
+struct Handle;
+
+#[derive(Dissolve, DissolveRef, DissolveMut)]
+struct Connection<T> {
+    #[dissolve(position = 1)]
+    #[dissolve_ref(skip)]
+    host: String,
+    #[dissolve(position = 0)]
+    #[dissolve_mut(position = 0)]
+    port: u16,
+    #[dissolve(skip)]
+    #[dissolve_ref(skip)]
+    #[dissolve_mut(skip)]
+    handle: Handle,
+    #[dissolve(skip)]
+    #[dissolve_ref(skip)]
+    #[dissolve_mut(skip)]
+    marker: PhantomData<T>,
+    #[dissolve(skip)]
+    #[dissolve_ref(skip)]
+    #[dissolve_mut(position = 2)]
+    #[cfg(not(test))]
+    retries: u8,
+}
+
+#[derive(Dissolve)]
+#[dissolve(parts = "PairParts")]
+struct Pair(#[dissolve(skip)] Handle, #[dissolve(position = 0)] u8, String);
+
+fn connection() -> Connection<()> {
+    Connection {
+        host: "localhost".to_owned(),
+        port: 80,
+        handle: Handle,
+        marker: PhantomData,
+        #[cfg(not(test))]
+        retries: 3,
+    }
+}
+
+fn main() {
+    let mut connection = connection();
+    let Connection { handle: Handle, .. } = &connection;
+
+    let (port, host, retries) = connection.dissolve_mut();
+    *port += 8000;
+    host.push_str(":8080");
+    *retries = 0;
+
+    let (port,): (&u16,) = connection.dissolve_ref();
+    assert!(*port == 8080);
+
+    let (port, host): (u16, String) = connection.dissolve();
+    assert!(port == 8080 && host == "localhost:8080");
+
+    let PairParts(number, text) = Pair(Handle, 1, "one".to_owned()).dissolve();
+    assert!(number == 1 && text == "one");
+}
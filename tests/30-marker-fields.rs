@@ -0,0 +1,69 @@
+//! Marker fields like `PhantomData` are left out unless asked for.
+
+use std::marker::{PhantomData, PhantomPinned};
+
+use derive_getters::{Getters, MutGetters, Dissolve, DissolveRef};
+
+#[derive(Getters, MutGetters, Dissolve, DissolveRef)]
+struct Id<T> {
+    value: u64,
+    marker: PhantomData<T>,
+    #[getter(include)]
+    #[getter_mut(include)]
+    #[dissolve(include)]
+    #[dissolve_ref(include)]
+    pinned: PhantomPinned,
+}
+
+#[derive(Getters)]
+#[getters(skip_underscore)]
+struct Cached {
+    name: String,
+    _cache: Vec<u8>,
+    #[getter(include)]
+    _hits: u32,
+}
+
+#[derive(Getters, Dissolve)]
+struct Unmarked {
+    _kept: u8,
+    _marker: PhantomData<()>,
+}
+
+trait NoMarker {
+    fn marker(&self) {}
+    fn marker_mut(&mut self) {}
+}
+
+impl<T> NoMarker for Id<T> {}
+
+trait NoCache {
+    fn _cache(&self) {}
+}
+
+impl NoCache for Cached {}
+
+fn main() {
+    let mut id: Id<String> = Id { value: 7, marker: PhantomData, pinned: PhantomPinned };
+    *id.value_mut() += 1;
+    let _: &PhantomPinned = id.pinned();
+    let _: &mut PhantomPinned = id.pinned_mut();
+
+    // The traits' methods are picked as no getter was generated for the marker.
+    id.marker();
+    id.marker_mut();
+
+    let (value, _): (&u64, &PhantomPinned) = id.dissolve_ref();
+    assert!(*value == 8);
+    let (value, _): (u64, PhantomPinned) = id.dissolve();
+    assert!(value == 8);
+
+    let cached = Cached { name: "name".to_owned(), _cache: Vec::new(), _hits: 2 };
+    cached._cache();
+    assert!(cached.name() == "name" && *cached._hits() == 2);
+
+    let unmarked = Unmarked { _kept: 1, _marker: PhantomData };
+    assert!(*unmarked._kept() == 1);
+    let (kept,): (u8,) = unmarked.dissolve();
+    assert!(kept == 1);
+}
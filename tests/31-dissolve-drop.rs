@@ -0,0 +1,91 @@
+//! Dissolving structs that implement `Drop`.
+
+use std::{
+    cell::Cell,
+    rc::Rc,
+};
+
+use derive_getters::Dissolve;
+
+/// Counts how many times it's dropped.
+struct Tracked(Rc<Cell<u32>>);
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.0.set(self.0.get() + 1);
+    }
+}
+
+#[derive(Dissolve)]
+#[dissolve(skip_drop)]
+struct Guard {
+    name: String,
+    #[dissolve(skip)]
+    tracked: Tracked,
+    flushed: Rc<Cell<bool>>,
+}
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        self.flushed.set(true);
+    }
+}
+
+#[derive(Dissolve)]
+#[dissolve(skip_drop, parts = "HandleParts")]
+struct Handle {
+    id: u32,
+    tracked: Tracked,
+}
+
+impl Drop for Handle {
+    fn drop(&mut self) {
+        panic!("never dropped when dissolved");
+    }
+}
+
+#[derive(Dissolve)]
+#[dissolve(take)]
+struct Buffer {
+    data: Vec<u8>,
+    #[cfg(not(test))]
+    label: String,
+    #[dissolve(skip)]
+    dropped_empty: Rc<Cell<bool>>,
+}
+
+impl Drop for Buffer {
+    fn drop(&mut self) {
+        self.dropped_empty.set(self.data.is_empty());
+    }
+}
+
+fn main() {
+    let drops = Rc::new(Cell::new(0));
+    let flushed = Rc::new(Cell::new(false));
+    let guard = Guard {
+        name: "guard".to_owned(),
+        tracked: Tracked(drops.clone()),
+        flushed: flushed.clone(),
+    };
+    let (name, _) = guard.dissolve();
+    assert!(name == "guard");
+    assert!(!flushed.get());
+    assert!(drops.get() == 1);
+
+    let handle = Handle { id: 3, tracked: Tracked(drops.clone()) };
+    let HandleParts { id, tracked } = handle.dissolve();
+    assert!(id == 3 && drops.get() == 1);
+    drop(tracked);
+    assert!(drops.get() == 2);
+
+    let dropped_empty = Rc::new(Cell::new(false));
+    let buffer = Buffer {
+        data: vec![1, 2],
+        label: "label".to_owned(),
+        dropped_empty: dropped_empty.clone(),
+    };
+    let (data, label) = buffer.dissolve();
+    assert!(data == [1, 2] && label == "label");
+    assert!(dropped_empty.get());
+}
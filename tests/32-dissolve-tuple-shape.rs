@@ -0,0 +1,81 @@
+//! One field tuples, unwrapped single fields and chunked tuples.
+
+use derive_getters::{Dissolve, DissolveRef, DissolveMut, New};
+
+#[derive(Dissolve, DissolveRef, DissolveMut, New, Debug, PartialEq)]
+#[dissolve(impl_from)]
+struct Meters {
+    value: f64,
+}
+
+#[derive(Dissolve, DissolveRef, DissolveMut)]
+#[dissolve(unwrap_single, impl_from)]
+#[dissolve_ref(unwrap_single, impl_from)]
+#[dissolve_mut(unwrap_single)]
+struct Seconds {
+    value: f64,
+    #[dissolve(skip)]
+    #[dissolve_ref(skip)]
+    #[dissolve_mut(skip)]
+    label: &'static str,
+}
+
+#[derive(Dissolve, DissolveRef, DissolveMut, Default)]
+#[dissolve(chunk = 12)]
+#[dissolve_ref(chunk = 12)]
+#[dissolve_mut(chunk = 4, unwrap_single)]
+struct Wide {
+    a: u8, b: u8, c: u8, d: u8, e: u8, f: u8, g: u8,
+    h: u8, i: u8, j: u8, k: u8, l: u8, m: u8, n: u8,
+}
+
+#[derive(Dissolve)]
+#[dissolve(chunk = 2)]
+struct Deep {
+    a: u8,
+    b: u8,
+    c: u8,
+    d: u8,
+    e: u8,
+}
+
+#[derive(Dissolve)]
+#[dissolve(chunk = 3)]
+struct Narrow {
+    a: u8,
+    b: u8,
+}
+
+fn main() {
+    let mut meters = Meters { value: 1.0 };
+    let (value,) = meters.dissolve_mut();
+    *value += 1.0;
+    assert!(meters.dissolve_ref() == (&2.0,));
+    let tuple: (f64,) = Meters::new(2.0).into();
+    assert!(Meters::from(tuple) == meters);
+    assert!(meters.dissolve() == (2.0,));
+
+    let mut seconds = Seconds { value: 1.5, label: "s" };
+    assert!(seconds.label == "s");
+    *seconds.dissolve_mut() += 1.0;
+    assert!(*seconds.dissolve_ref() == 2.5);
+    let value: &f64 = (&seconds).into();
+    assert!(*value == 2.5);
+    let value: f64 = seconds.into();
+    assert!(value == 2.5);
+
+    let mut wide = Wide { n: 14, ..Wide::default() };
+    let ((a, ..), _, _, (_, n)) = wide.dissolve_mut();
+    *a = 1;
+    *n += 1;
+    let ((a, ..), (.., n)) = wide.dissolve_ref();
+    assert!(*a == 1 && *n == 15);
+    let dissolved = wide.dissolve();
+    assert!(dissolved == ((1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0), (0, 15)));
+    assert!(format!("{:?}", dissolved).starts_with("((1, 0,"));
+
+    let deep = Deep { a: 1, b: 2, c: 3, d: 4, e: 5 };
+    assert!(deep.dissolve() == (((1, 2), (3, 4)), ((5,),)));
+
+    assert!(Narrow { a: 1, b: 2 }.dissolve() == (1, 2));
+}
@@ -0,0 +1,72 @@
+//! Structs whose fields share a type, used as arrays and iterated over.
+
+use std::marker::PhantomData;
+
+use derive_getters::Homogeneous;
+
+#[derive(Homogeneous, Debug, PartialEq)]
+struct Rgb {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+#[derive(Homogeneous)]
+#[homogeneous(vis = "pub(crate)")]
+struct Vec3<T> where T: Copy {
+    x: T,
+    y: T,
+    #[homogeneous(position = 0)]
+    z: T,
+}
+
+#[derive(Homogeneous)]
+struct Tagged<'a, U> {
+    first: &'a str,
+    #[homogeneous(skip)]
+    count: usize,
+    second: &'a str,
+    unit: PhantomData<U>,
+    #[cfg(not(test))]
+    third: &'a str,
+}
+
+#[derive(Homogeneous)]
+struct Pair(String, String);
+
+fn main() {
+    let mut rgb = Rgb { r: 1, g: 2, b: 3 };
+    assert!(rgb.as_array_refs() == [&1, &2, &3]);
+    assert!(rgb.iter_fields().copied().sum::<u8>() == 6);
+    for value in rgb.iter_fields_mut() {
+        *value *= 2;
+    }
+    rgb[0] += 1;
+    assert!(rgb[0] == 3 && rgb[2] == 6);
+    assert!(rgb == Rgb { r: 3, g: 4, b: 6 });
+    assert!(rgb.into_array() == [3, 4, 6]);
+
+    let v = Vec3 { x: 1.0, y: 2.0, z: 3.0 };
+    assert!(v.into_array() == [3.0, 1.0, 2.0]);
+
+    let tagged: Tagged<()> = Tagged {
+        first: "a",
+        count: 3,
+        second: "b",
+        unit: PhantomData,
+        third: "c",
+    };
+    assert!(tagged.count == 3);
+    assert!(tagged.iter_fields().copied().collect::<Vec<&str>>() == ["a", "b", "c"]);
+    assert!(tagged[1] == "b");
+
+    let mut pair = Pair("left".to_owned(), "right".to_owned());
+    pair[1].push('!');
+    let [left, right] = pair.into_array();
+    assert!(left == "left" && right == "right!");
+
+    // Indexing past the last field panics, quietly here.
+    std::panic::set_hook(Box::new(|_| ()));
+    let result = std::panic::catch_unwind(|| Rgb { r: 0, g: 0, b: 0 }[3]);
+    assert!(result.is_err());
+}
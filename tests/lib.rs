@@ -8,7 +8,32 @@ fn tests() {
     t.pass("tests/05-skip-rename-attributes.rs");
     t.pass("tests/06-plays-with-others.rs");
     t.pass("tests/07-dissolve-basic.rs");
-    t.pass("tests/08-dissolve-generic-and-ref.rs");    
+    t.pass("tests/08-dissolve-generic-and-ref.rs");
+    t.pass("tests/09-copy-clone-getters.rs");
+    t.pass("tests/10-deref-getters.rs");
+    t.pass("tests/11-visibility.rs");
+    t.pass("tests/12-struct-naming.rs");
+    t.pass("tests/13-forward-docs.rs");
+    t.pass("tests/14-cfg-fields.rs");
+    t.pass("tests/15-method-attributes.rs");
+    t.pass("tests/16-getter-trait.rs");
+    t.pass("tests/17-tuple-structs.rs");
+    t.pass("tests/18-enum-common-fields.rs");
+    t.pass("tests/19-enum-variants.rs");
+    t.pass("tests/20-union-getters.rs");
+    t.pass("tests/21-const-generics.rs");
+    t.pass("tests/22-reference-fields.rs");
+    t.pass("tests/23-mut-getters.rs");
+    t.pass("tests/24-setters.rs");
+    t.pass("tests/25-with-methods.rs");
+    t.pass("tests/26-new-constructor.rs");
+    t.pass("tests/27-dissolve-from.rs");
+    t.pass("tests/28-dissolve-parts.rs");
+    t.pass("tests/29-dissolve-field-selection.rs");
+    t.pass("tests/30-marker-fields.rs");
+    t.pass("tests/31-dissolve-drop.rs");
+    t.pass("tests/32-dissolve-tuple-shape.rs");
+    t.pass("tests/33-homogeneous.rs");
 }
 
 #[test]